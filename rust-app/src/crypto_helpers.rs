@@ -23,8 +23,12 @@ impl Address<PKH, ledger_device_sdk::ecc::ECPublicKey<65, 'E'>> for PKH {
 
 #[allow(dead_code)]
 pub fn get_pkh(key: &ledger_device_sdk::ecc::ECPublicKey<65, 'E'>) -> Result<PKH, SyscallError> {
+    Ok(pkh_from_public_key_bytes(ed25519_public_key_bytes(key)))
+}
+
+// An address is the first 20 bytes of the SHA-256 of the raw ed25519 public key.
+pub fn pkh_from_public_key_bytes(key_bytes: &[u8]) -> PKH {
     let mut public_key_hash = [0; 32];
-    let key_bytes = ed25519_public_key_bytes(key);
     unsafe {
         let _len = cx_hash_sha256(
             key_bytes.as_ptr(),
@@ -35,7 +39,34 @@ pub fn get_pkh(key: &ledger_device_sdk::ecc::ECPublicKey<65, 'E'>) -> Result<PKH
     }
     let mut rv = PKH([0; 20]);
     rv.0.clone_from_slice(&public_key_hash[0..20]);
-    Ok(rv)
+    rv
+}
+
+impl PKH {
+    // Addresses in transactions are hex strings; accept either case.
+    pub fn matches_hex(&self, hex: &[u8]) -> bool {
+        hex_decode::<20>(hex) == Some(self.0)
+    }
+}
+
+pub fn hex_decode<const N: usize>(hex: &[u8]) -> Option<[u8; N]> {
+    if hex.len() != 2 * N {
+        return None;
+    }
+    let mut rv = [0; N];
+    for (byte, pair) in rv.iter_mut().zip(hex.chunks_exact(2)) {
+        *byte = (hex_digit(pair[0])? << 4) | hex_digit(pair[1])?;
+    }
+    Some(rv)
+}
+
+fn hex_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
 }
 
 impl fmt::Display for PKH {
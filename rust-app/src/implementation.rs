@@ -1,6 +1,9 @@
-use crate::crypto_helpers::PKH;
+use crate::crypto_helpers::{hex_decode, pkh_from_public_key_bytes, PKH};
 use crate::interface::*;
+use crate::rejection::*;
+use crate::settings::Settings;
 use crate::utils::*;
+use crate::warnings::*;
 use crate::*;
 use arrayvec::ArrayString;
 use arrayvec::ArrayVec;
@@ -9,6 +12,7 @@ use core::fmt::Write;
 use ledger_crypto_helpers::common::{try_option, Address, CryptographyError};
 use ledger_crypto_helpers::ed25519::*;
 use ledger_crypto_helpers::eddsa::{ed25519_public_key_bytes, with_public_keys};
+use ledger_crypto_helpers::hasher::{Hash, Hasher, SHA256};
 use ledger_parser_combinators::interp_parser::{
     set_from_thunk, Action, DefaultInterp, DropInterp, DynBind, DynParser, InterpParser,
     MoveAction, ObserveLengthedBytes, ParseResult, ParserCommon, Preaction, SubInterp, OOB,
@@ -44,14 +48,14 @@ pub const fn get_address_impl<const PROMPT: bool>() -> GetAddressImplT {
             |path: &ArrayVec<u32, 10>, destination: &mut Option<ArrayVec<u8, 128>>| -> Option<()> {
                 if !path.starts_with(&BIP32_PREFIX[0..2]) {
                     // There isn't a _no_throw variation of the below, so avoid a throw on incorrect input.
-                    return None;
+                    return reject(AppSW::BadDerivationPath);
                 }
                 with_public_keys(path, false, |key: &_, pkh: &PKH| {
                     try_option(|| -> Option<()> {
                         if PROMPT {
                             scroller("Provide Public Key", |_w| Ok(()))?;
                             scroller_paginated("Address", |w| Ok(write!(w, "{pkh}")?))?;
-                            final_accept_prompt(&[])?;
+                            final_accept_prompt(&[]).or_else(|| reject(AppSW::UserRejected))?;
                         }
 
                         let rv = destination.insert(ArrayVec::new());
@@ -59,17 +63,10 @@ pub const fn get_address_impl<const PROMPT: bool>() -> GetAddressImplT {
                         // Should return the format that the chain customarily uses for public keys; for
                         // ed25519 that's usually r | s with no prefix, which isn't quite our internal
                         // representation.
-                        let key_bytes = ed25519_public_key_bytes(key);
-
-                        rv.try_push(u8::try_from(key_bytes.len()).ok()?).ok()?;
-                        rv.try_extend_from_slice(key_bytes).ok()?;
-
+                        //
                         // And we'll send the address along; in our case it happens to be the same as the
                         // public key, but in general it's something computed from the public key.
-                        let binary_address = pkh.get_binary_address();
-                        rv.try_push(u8::try_from(binary_address.len()).ok()?).ok()?;
-                        rv.try_extend_from_slice(binary_address).ok()?;
-                        Some(())
+                        push_public_key(rv, ed25519_public_key_bytes(key), pkh.get_binary_address())
                     }())
                 })
                 .ok()
@@ -78,6 +75,52 @@ pub const fn get_address_impl<const PROMPT: bool>() -> GetAddressImplT {
     )
 }
 
+fn push_public_key<const N: usize>(
+    rv: &mut ArrayVec<u8, N>,
+    key_bytes: &[u8],
+    binary_address: &[u8],
+) -> Option<()> {
+    rv.try_push(u8::try_from(key_bytes.len()).ok()?).ok()?;
+    rv.try_extend_from_slice(key_bytes).ok()?;
+    rv.try_push(u8::try_from(binary_address.len()).ok()?).ok()?;
+    rv.try_extend_from_slice(binary_address).ok()
+}
+
+// Each key in a batch is returned as GET_PUBKEY returns it: a length byte and the 32 byte key,
+// then a length byte and the 20 byte address.
+const PUBKEY_ENTRY_SIZE: usize = 1 + 32 + 1 + 20;
+
+// The largest result of any command; results are kept in the parser state's place until sent.
+pub const MAX_RESULT_SIZE: usize = MAX_PUBKEY_BATCH * PUBKEY_ENTRY_SIZE;
+
+pub type GetPubkeysImplT = impl InterpParser<Bip32Keys, Returning = ArrayVec<u8, MAX_RESULT_SIZE>>;
+
+// Like GET_PUBKEY, with no prompt, for each path of a batch; all of the paths must be valid.
+pub const GET_PUBKEYS_IMPL: GetPubkeysImplT = Action(
+    SubInterp(SubInterp(DefaultInterp)),
+    mkfn(
+        |paths: &ArrayVec<ArrayVec<u32, 10>, MAX_PUBKEY_BATCH>,
+         destination: &mut Option<ArrayVec<u8, MAX_RESULT_SIZE>>|
+         -> Option<()> {
+            let rv = destination.insert(ArrayVec::new());
+            for path in paths {
+                if !path.starts_with(&BIP32_PREFIX[0..2]) {
+                    return reject(AppSW::BadDerivationPath);
+                }
+                with_public_keys(path, false, |key: &_, pkh: &PKH| {
+                    try_option(push_public_key(
+                        rv,
+                        ed25519_public_key_bytes(key),
+                        pkh.get_binary_address(),
+                    ))
+                })
+                .ok()?;
+            }
+            Some(())
+        },
+    ),
+);
+
 //const fn show_address<const TITLE: &'static str>() -> impl JsonInterp<JsonString, State: Debug, Returning: Debug>
 #[allow(clippy::type_complexity)]
 const fn show_address<const TITLE: &'static str>(
@@ -94,6 +137,19 @@ const fn show_address<const TITLE: &'static str>(
     )
 }
 
+// Writes untrusted text so that it can't pass itself off as other screens: anything outside
+// printable ASCII, and the backslash itself, is shown as an escape sequence.
+fn write_escaped<W: Write>(w: &mut W, bytes: &[u8]) -> core::fmt::Result {
+    for &c in bytes {
+        match c {
+            b'\\' => w.write_str("\\\\")?,
+            b' '..=b'~' => w.write_char(c as char)?,
+            _ => write!(w, "\\x{:02x}", c)?,
+        }
+    }
+    Ok(())
+}
+
 /* This would be used to show fees; not currently used.
 const AMOUNT_ACTION: Action<AmountType<JsonStringAccumulate<64>, JsonStringAccumulate<64>>,
                                   fn(& AmountType<Option<ArrayVec<u8, 64>>, Option<ArrayVec<u8, 64>>>, &mut Option<()>) -> Option<()>> =
@@ -105,7 +161,7 @@ const AMOUNT_ACTION: Action<AmountType<JsonStringAccumulate<64>, JsonStringAccum
         });
 */
 
-type SendMessageAction = impl JsonInterp<SendValueSchema, State: Debug, Returning = ()>;
+type SendMessageAction = impl JsonInterp<SendValueSchema, State: Debug, Returning = MessageSummary>;
 const SEND_MESSAGE_ACTION: SendMessageAction = Preaction(
     || scroller("Transfer", |w| Ok(write!(w, "POKT")?)),
     Action(
@@ -121,7 +177,14 @@ const SEND_MESSAGE_ACTION: SendMessageAction = Preaction(
                 Option<ArrayVec<u8, 64>>,
                 Option<ArrayVec<u8, 64>>,
             >,
-             destination: &mut Option<()>| {
+             destination: &mut Option<MessageSummary>| {
+                // A transfer from some other account would fail on-chain at best, and at worst
+                // misleads the user about who pays; only let it through if they've opted in.
+                let from_self =
+                    unsafe { SIGNING_ADDRESS.matches_hex(o.field_from_address.as_ref()?) };
+                if !from_self && !Settings.allow_sender_mismatch() {
+                    return reject(AppSW::SenderMismatch);
+                }
                 scroller_paginated("From", |w| {
                     Ok(write!(
                         w,
@@ -129,6 +192,9 @@ const SEND_MESSAGE_ACTION: SendMessageAction = Preaction(
                         from_utf8(o.field_from_address.as_ref().ok_or(ScrollerError)?)?
                     )?)
                 })?;
+                if !from_self {
+                    show_warning(Warning::SenderMismatch)?;
+                }
                 scroller_paginated("To", |w| {
                     Ok(write!(
                         w,
@@ -141,7 +207,10 @@ const SEND_MESSAGE_ACTION: SendMessageAction = Preaction(
                         .map_err(|_| ScrollerError)?;
                     Ok(write!(w, "POKT {}", from_utf8(&x)?)?)
                 })?;
-                *destination = Some(());
+                *destination = Some(MessageSummary {
+                    amount: o.field_amount.clone(),
+                    to_self: unsafe { SIGNING_ADDRESS.matches_hex(o.field_to_address.as_ref()?) },
+                });
                 Some(())
             },
         ),
@@ -213,22 +282,274 @@ fn get_amount_in_decimals(amount: &ArrayVec<u8, 64>) -> Result<ArrayVec<u8, 64>,
     Ok(dec_value)
 }
 
-#[cfg(target_os = "nanos")]
-const STAKE_CHAINS_LIST_SIZE: usize = 4;
-#[cfg(not(target_os = "nanos"))]
-const STAKE_CHAINS_LIST_SIZE: usize = 50;
+// Decodes padded base64, which is how amino encodes byte slices in JSON.
+fn base64_decode<const N: usize>(input: &[u8]) -> Result<ArrayVec<u8, N>, ()> {
+    fn sextet(c: u8) -> Result<u32, ()> {
+        match c {
+            b'A'..=b'Z' => Ok((c - b'A') as u32),
+            b'a'..=b'z' => Ok((c - b'a' + 26) as u32),
+            b'0'..=b'9' => Ok((c - b'0' + 52) as u32),
+            b'+' => Ok(62),
+            b'/' => Ok(63),
+            _ => Err(()),
+        }
+    }
+    if input.len() % 4 != 0 {
+        return Err(());
+    }
+    let mut rv = ArrayVec::new();
+    for (i, quad) in input.chunks_exact(4).enumerate() {
+        let pad = quad.iter().rev().take_while(|c| **c == b'=').count();
+        if pad > 2 || (pad > 0 && (i + 1) * 4 != input.len()) {
+            return Err(());
+        }
+        let mut acc: u32 = 0;
+        for c in &quad[..4 - pad] {
+            acc = (acc << 6) | sextet(*c)?;
+        }
+        acc <<= 6 * pad as u32;
+        let bytes = [(acc >> 16) as u8, (acc >> 8) as u8, acc as u8];
+        rv.try_extend_from_slice(&bytes[..3 - pad])
+            .map_err(|_| ())?;
+    }
+    Ok(rv)
+}
+
+// Adds two amounts given as strings of decimal digits, without going through a fixed-width
+// integer.
+fn add_amounts(a: &[u8], b: &[u8]) -> Result<ArrayVec<u8, 64>, ()> {
+    fn digit(c: u8) -> Result<u8, ()> {
+        if c.is_ascii_digit() {
+            Ok(c - b'0')
+        } else {
+            Err(())
+        }
+    }
+    if a.is_empty() || b.is_empty() {
+        return Err(());
+    }
+    // Digits of the sum, least significant first.
+    let mut reversed: ArrayVec<u8, 65> = ArrayVec::new();
+    let (mut i, mut j, mut carry) = (a.len(), b.len(), 0);
+    while i > 0 || j > 0 || carry > 0 {
+        let mut d = carry;
+        if i > 0 {
+            i -= 1;
+            d += digit(a[i])?;
+        }
+        if j > 0 {
+            j -= 1;
+            d += digit(b[j])?;
+        }
+        reversed.push(b'0' + d % 10);
+        carry = d / 10;
+    }
+    while reversed.len() > 1 && reversed.last() == Some(&b'0') {
+        reversed.pop();
+    }
+    let mut sum = ArrayVec::new();
+    for c in reversed.iter().rev() {
+        sum.try_push(*c).map_err(|_| ())?;
+    }
+    Ok(sum)
+}
 
 #[cfg(target_os = "nanos")]
 const STAKE_SERVICE_URL_SIZE: usize = 64;
 #[cfg(not(target_os = "nanos"))]
 const STAKE_SERVICE_URL_SIZE: usize = 256;
 
-type StakeMessageAction = impl JsonInterp<StakeValueSchema, State: Debug>;
+// Well-known RelayChain IDs, so that operators can check at a glance which services a stake
+// covers.
+const RELAY_CHAINS: &[(&[u8], &str)] = &[
+    (b"0001", "Pocket Network"),
+    (b"0003", "Avalanche"),
+    (b"0004", "BNB Smart Chain"),
+    (b"0005", "Fuse"),
+    (b"0006", "Solana"),
+    (b"0009", "Polygon"),
+    (b"000B", "Polygon Archival"),
+    (b"0010", "BNB Smart Chain Archival"),
+    (b"0021", "Ethereum"),
+    (b"0022", "Ethereum Archival"),
+    (b"0023", "Ethereum Ropsten"),
+    (b"0024", "Ethereum Kovan"),
+    (b"0025", "Ethereum Rinkeby"),
+    (b"0026", "Ethereum Goerli"),
+    (b"0027", "Gnosis Chain"),
+    (b"0028", "Ethereum Archival Trace"),
+    (b"0040", "Harmony Shard 0"),
+    (b"0044", "IoTeX"),
+    (b"0046", "Evmos"),
+    (b"0047", "OKXChain"),
+    (b"0048", "Boba"),
+    (b"0049", "Fantom"),
+    (b"0053", "Optimism"),
+    (b"0054", "Osmosis"),
+    (b"0066", "Arbitrum One"),
+];
+
+fn relay_chain_name(chain: &[u8]) -> Option<&'static str> {
+    RELAY_CHAINS
+        .iter()
+        .find(|(id, _)| id.eq_ignore_ascii_case(chain))
+        .map(|(_, name)| *name)
+}
+
+// Chains are shown one at a time as they are parsed, so a stake may list any number of them. The
+// element action can't see its position in the array, so the running count is kept here.
+static mut STAKE_CHAIN_COUNT: usize = 0;
+
+type StakeChainsAction = impl JsonInterp<JsonArray<JsonString>, State: Debug, Returning = usize>;
+const STAKE_CHAINS_ACTION: StakeChainsAction = Preaction(
+    || {
+        unsafe {
+            STAKE_CHAIN_COUNT = 0;
+        }
+        Some(())
+    },
+    Action(
+        SubInterp(Action(
+            JsonStringAccumulate::<4>,
+            mkvfn(|chain: &ArrayVec<u8, 4>, destination| {
+                let count = unsafe {
+                    STAKE_CHAIN_COUNT += 1;
+                    STAKE_CHAIN_COUNT
+                };
+                let mut buffer: ArrayString<22> = ArrayString::new();
+                write!(mk_prompt_write(&mut buffer), "Chain {}", count).ok()?;
+                scroller(&buffer, |w| {
+                    let name = relay_chain_name(chain).unwrap_or("UNKNOWN");
+                    Ok(write!(w, "{}: {}", from_utf8(chain.as_ref())?, name)?)
+                })?;
+                *destination = Some(());
+                Some(())
+            }),
+        )),
+        mkvfn(|_, destination: &mut Option<usize>| {
+            let count = unsafe { STAKE_CHAIN_COUNT };
+            if count == 0 {
+                return reject(AppSW::InvalidInput);
+            }
+            scroller("Total chains", |w| Ok(write!(w, "{}", count)?))?;
+            *destination = Some(count);
+            Some(())
+        }),
+    ),
+);
+
+type StakePublicKey = PublicKey<Option<ArrayVec<u8, 64>>, Option<ArrayVec<u8, 64>>>;
+
+// Shared by the current and the legacy (pre-8.0, no output address) stake messages.
+fn show_stake(
+    public_key: &StakePublicKey,
+    service_url: &ArrayVec<u8, STAKE_SERVICE_URL_SIZE>,
+    value: &ArrayVec<u8, 64>,
+    output_address: Option<&ArrayVec<u8, 64>>,
+) -> Option<()> {
+    unsafe {
+        scroller_paginated("From", |w| Ok(write!(w, "{}", SIGNING_ADDRESS)?))?;
+    }
+    scroller("Amount", |w| {
+        let x = get_amount_in_decimals(value).map_err(|_| ScrollerError)?;
+        Ok(write!(w, "POKT {}", from_utf8(&x)?)?)
+    })?;
+    // The operator is identified by its address everywhere else, so show that alongside the key;
+    // this only works for the one key type pocket-core accepts.
+    if public_key.field_type.as_ref()?.as_slice() != b"crypto/ed25519_public_key" {
+        return reject(AppSW::InvalidInput);
+    }
+    let operator_key = public_key.field_value.as_ref()?;
+    let operator_key_bytes =
+        hex_decode::<32>(operator_key).or_else(|| reject(AppSW::InvalidInput))?;
+    let operator_address = pkh_from_public_key_bytes(&operator_key_bytes);
+    scroller("Node Operator", |w| {
+        Ok(write!(
+            w,
+            "{} ({})",
+            from_utf8(operator_key)?,
+            from_utf8(public_key.field_type.as_ref().ok_or(ScrollerError)?)?
+        )?)
+    })?;
+    scroller_paginated("Operator Address", |w| {
+        Ok(write!(w, "{}", operator_address)?)
+    })?;
+    if let Some(output_address) = output_address {
+        scroller("Output Address", |w| {
+            Ok(write!(w, "{}", from_utf8(output_address)?)?)
+        })?;
+    }
+    let url = parse_service_url(service_url).or_else(|| reject(AppSW::InvalidInput))?;
+    scroller("Service URL", |w| {
+        Ok(write!(w, "{}", from_utf8(service_url)?)?)
+    })?;
+    scroller_paginated("Service Host", |w| {
+        Ok(write!(w, "{}", from_utf8(url.host)?)?)
+    })?;
+    if url.scheme != b"https" {
+        show_warning(Warning::InsecureServiceUrl)?;
+    }
+    if is_ip_literal(url.host) {
+        show_warning(Warning::IpServiceHost)?;
+    }
+    if url.port.is_none() {
+        show_warning(Warning::NoServicePort)?;
+    }
+    Some(())
+}
+
+struct ServiceUrl<'a> {
+    scheme: &'a [u8],
+    host: &'a [u8],
+    port: Option<&'a [u8]>,
+}
+
+// Just enough URL parsing to pick out the host a node will be reached at; anything that isn't a
+// plain http(s) URL with a well-formed port is rejected.
+fn parse_service_url(url: &[u8]) -> Option<ServiceUrl<'_>> {
+    let scheme_end = url.windows(3).position(|w| w == b"://")?;
+    let scheme = &url[..scheme_end];
+    if scheme != b"http" && scheme != b"https" {
+        return None;
+    }
+    let rest = &url[scheme_end + 3..];
+    let authority = rest.split(|c| matches!(c, b'/' | b'?' | b'#')).next()?;
+    // Userinfo is legal but would let "https://trusted.com@other.com" pass for trusted.com, so
+    // only what follows it is treated as the host.
+    let host_port = authority.rsplit(|c| *c == b'@').next()?;
+    let port_start = match host_port.iter().rposition(|c| *c == b':') {
+        // A colon inside an IPv6 literal is not a port separator.
+        Some(i) if !host_port[i..].contains(&b']') => Some(i),
+        _ => None,
+    };
+    let (host, port) = match port_start {
+        Some(i) => (&host_port[..i], Some(&host_port[i + 1..])),
+        None => (host_port, None),
+    };
+    if host.is_empty() {
+        return None;
+    }
+    if let Some(port) = port {
+        // Checked for digits first, as parsing would also take a leading '+'. Port 0 can't be
+        // connected to.
+        if !port.iter().all(u8::is_ascii_digit) || from_utf8(port).ok()?.parse::<u16>().ok()? == 0 {
+            return None;
+        }
+    }
+    Some(ServiceUrl { scheme, host, port })
+}
+
+fn is_ip_literal(host: &[u8]) -> bool {
+    host.starts_with(b"[") || host.iter().all(|c| c.is_ascii_digit() || *c == b'.')
+}
+
+type StakeMessageAction =
+    impl JsonInterp<StakeValueSchema, State: Debug, Returning = MessageSummary>;
 const STAKE_MESSAGE_ACTION: StakeMessageAction = Preaction(
     || scroller("Stake", |w| Ok(write!(w, "POKT")?)),
     Action(
         StakeValueInterp {
-            field_chains: AccumulateArray(JsonStringAccumulate::<4>),
+            field_chains: STAKE_CHAINS_ACTION,
             field_public_key: PublicKeyInterp {
                 field_type: JsonStringAccumulate::<64>,
                 field_value: JsonStringAccumulate::<64>,
@@ -236,31 +557,178 @@ const STAKE_MESSAGE_ACTION: StakeMessageAction = Preaction(
             field_service_url: JsonStringAccumulate::<STAKE_SERVICE_URL_SIZE>,
             field_value: JsonStringAccumulate::<64>,
             field_output_address: JsonStringAccumulate::<64>,
+            field_reward_delegators: RewardDelegatorsInterp,
         },
         #[allow(clippy::type_complexity)]
         mkfn(
             |o: &StakeValue<
-                Option<ArrayVec<ArrayVec<u8, 4>, STAKE_CHAINS_LIST_SIZE>>,
-                Option<PublicKey<Option<ArrayVec<u8, 64>>, Option<ArrayVec<u8, 64>>>>,
+                Option<usize>,
+                Option<StakePublicKey>,
                 Option<ArrayVec<u8, STAKE_SERVICE_URL_SIZE>>,
                 Option<ArrayVec<u8, 64>>,
                 Option<ArrayVec<u8, 64>>,
+                Option<()>,
+            >,
+             destination: &mut Option<MessageSummary>| {
+                o.field_chains?;
+                show_stake(
+                    o.field_public_key.as_ref()?,
+                    o.field_service_url.as_ref()?,
+                    o.field_value.as_ref()?,
+                    Some(o.field_output_address.as_ref()?),
+                )?;
+                *destination = Some(MessageSummary {
+                    amount: o.field_value.clone(),
+                    to_self: false,
+                });
+                Some(())
+            },
+        ),
+    ),
+);
+
+type LegacyStakeMessageAction =
+    impl JsonInterp<LegacyStakeValueSchema, State: Debug, Returning = MessageSummary>;
+const LEGACY_STAKE_MESSAGE_ACTION: LegacyStakeMessageAction = Preaction(
+    || scroller("Stake", |w| Ok(write!(w, "POKT")?)),
+    Action(
+        LegacyStakeValueInterp {
+            field_chains: STAKE_CHAINS_ACTION,
+            field_public_key: PublicKeyInterp {
+                field_type: JsonStringAccumulate::<64>,
+                field_value: JsonStringAccumulate::<64>,
+            },
+            field_service_url: JsonStringAccumulate::<STAKE_SERVICE_URL_SIZE>,
+            field_value: JsonStringAccumulate::<64>,
+        },
+        #[allow(clippy::type_complexity)]
+        mkfn(
+            |o: &LegacyStakeValue<
+                Option<usize>,
+                Option<StakePublicKey>,
+                Option<ArrayVec<u8, STAKE_SERVICE_URL_SIZE>>,
+                Option<ArrayVec<u8, 64>>,
             >,
+             destination: &mut Option<MessageSummary>| {
+                o.field_chains?;
+                show_stake(
+                    o.field_public_key.as_ref()?,
+                    o.field_service_url.as_ref()?,
+                    o.field_value.as_ref()?,
+                    None,
+                )?;
+                *destination = Some(MessageSummary {
+                    amount: o.field_value.clone(),
+                    to_self: false,
+                });
+                Some(())
+            },
+        ),
+    ),
+);
+
+// Since 8.0 either the operator or the output address may sign for a node. These messages don't
+// carry the output address, so all that can be checked is whether this device is the operator.
+fn show_signer(signer: &[u8], operator: &[u8]) -> Option<()> {
+    if unsafe { SIGNING_ADDRESS.matches_hex(signer) } {
+        let role = if signer.eq_ignore_ascii_case(operator) {
+            "operator"
+        } else {
+            "not operator"
+        };
+        scroller("Signer", |w| Ok(write!(w, "this device ({})", role)?))
+    } else {
+        scroller("Signer address", |w| {
+            Ok(write!(w, "{}", from_utf8(signer)?)?)
+        })?;
+        show_warning(Warning::SignerMismatch)
+    }
+}
+
+type UnstakeMessageAction = impl JsonInterp<UnstakeValueSchema, State: Debug>;
+const UNSTAKE_MESSAGE_ACTION: UnstakeMessageAction = Preaction(
+    || scroller("Unstake", |w| Ok(write!(w, "POKT")?)),
+    Action(
+        UnstakeValueInterp {
+            field_signer_address: JsonStringAccumulate::<64>,
+            field_validator_address: JsonStringAccumulate::<64>,
+        },
+        mkfn(
+            |o: &UnstakeValue<Option<ArrayVec<u8, 64>>, Option<ArrayVec<u8, 64>>>,
              destination: &mut Option<()>| {
-                let chains = o.field_chains.as_ref()?.as_slice();
-                if chains.is_empty() {
-                    return None;
-                }
+                let operator = o.field_validator_address.as_ref()?;
+                // Pre-8.0 messages have no signer field; the operator signed for itself.
+                show_signer(
+                    o.field_signer_address.as_ref().unwrap_or(operator),
+                    operator,
+                )?;
+                scroller("Unstake address", |w| {
+                    Ok(write!(w, "{}", from_utf8(operator)?)?)
+                })?;
+                *destination = Some(());
+                Some(())
+            },
+        ),
+    ),
+);
+
+type UnjailMessageAction = impl JsonInterp<UnjailValueSchema, State: Debug>;
+const UNJAIL_MESSAGE_ACTION: UnjailMessageAction = Preaction(
+    || scroller("Unjail", |w| Ok(write!(w, "Transaction")?)),
+    Action(
+        UnjailValueInterp {
+            field_address: JsonStringAccumulate::<64>,
+            field_signer_address: JsonStringAccumulate::<64>,
+        },
+        mkfn(
+            |o: &UnjailValue<Option<ArrayVec<u8, 64>>, Option<ArrayVec<u8, 64>>>,
+             destination: &mut Option<()>| {
+                let operator = o.field_address.as_ref()?;
+                scroller("Address", |w| Ok(write!(w, "{}", from_utf8(operator)?)?))?;
+                // Pre-8.0 messages have no signer field; the operator signed for itself.
+                show_signer(
+                    o.field_signer_address.as_ref().unwrap_or(operator),
+                    operator,
+                )?;
+                *destination = Some(());
+                Some(())
+            },
+        ),
+    ),
+);
+
+type AppStakeMessageAction =
+    impl JsonInterp<AppStakeValueSchema, State: Debug, Returning = MessageSummary>;
+const APP_STAKE_MESSAGE_ACTION: AppStakeMessageAction = Preaction(
+    || scroller("App Stake", |w| Ok(write!(w, "POKT")?)),
+    Action(
+        AppStakeValueInterp {
+            field_chains: STAKE_CHAINS_ACTION,
+            field_pubkey: PublicKeyInterp {
+                field_type: JsonStringAccumulate::<64>,
+                field_value: JsonStringAccumulate::<64>,
+            },
+            field_value: JsonStringAccumulate::<64>,
+        },
+        #[allow(clippy::type_complexity)]
+        mkfn(
+            |o: &AppStakeValue<
+                Option<usize>,
+                Option<PublicKey<Option<ArrayVec<u8, 64>>, Option<ArrayVec<u8, 64>>>>,
+                Option<ArrayVec<u8, 64>>,
+            >,
+             destination: &mut Option<MessageSummary>| {
+                o.field_chains?;
                 unsafe {
-                    scroller_paginated("From", |w| Ok(write!(w, "{}", SIGNING_ADDRESS)?))?;
+                    scroller_paginated("Signer", |w| Ok(write!(w, "{}", SIGNING_ADDRESS)?))?;
                 }
                 scroller("Amount", |w| {
                     let x = get_amount_in_decimals(o.field_value.as_ref().ok_or(ScrollerError)?)
                         .map_err(|_| ScrollerError)?;
                     Ok(write!(w, "POKT {}", from_utf8(&x)?)?)
                 })?;
-                scroller("Node Operator", |w| {
-                    let x = o.field_public_key.as_ref().ok_or(ScrollerError)?;
+                scroller("App Public Key", |w| {
+                    let x = o.field_pubkey.as_ref().ok_or(ScrollerError)?;
                     Ok(write!(
                         w,
                         "{} ({})",
@@ -268,34 +736,141 @@ const STAKE_MESSAGE_ACTION: StakeMessageAction = Preaction(
                         from_utf8(x.field_type.as_ref().ok_or(ScrollerError)?)?
                     )?)
                 })?;
-                scroller("Output Address", |w| {
+                *destination = Some(MessageSummary {
+                    amount: o.field_value.clone(),
+                    to_self: false,
+                });
+                Some(())
+            },
+        ),
+    ),
+);
+
+type AppUnstakeMessageAction = impl JsonInterp<AppUnstakeValueSchema, State: Debug>;
+const APP_UNSTAKE_MESSAGE_ACTION: AppUnstakeMessageAction = Preaction(
+    || scroller("App Unstake", |w| Ok(write!(w, "POKT")?)),
+    AppUnstakeValueInterp {
+        field_application_address: show_address::<"App address">(),
+    },
+);
+
+type AppUnjailMessageAction = impl JsonInterp<AppUnjailValueSchema, State: Debug>;
+const APP_UNJAIL_MESSAGE_ACTION: AppUnjailMessageAction = Preaction(
+    || scroller("App Unjail", |w| Ok(write!(w, "Transaction")?)),
+    AppUnjailValueInterp {
+        field_address: show_address::<"App address">(),
+    },
+);
+
+type DaoTransferMessageAction =
+    impl JsonInterp<DaoTransferValueSchema, State: Debug, Returning = MessageSummary>;
+const DAO_TRANSFER_MESSAGE_ACTION: DaoTransferMessageAction = Preaction(
+    || scroller("DAO Treasury", |w| Ok(write!(w, "POKT")?)),
+    Action(
+        DaoTransferValueInterp {
+            field_action: JsonStringAccumulate::<16>,
+            field_amount: JsonStringAccumulate::<64>,
+            field_from_address: JsonStringAccumulate::<64>,
+            field_to_address: JsonStringAccumulate::<64>,
+        },
+        #[allow(clippy::type_complexity)]
+        mkfn(
+            |o: &DaoTransferValue<
+                Option<ArrayVec<u8, 16>>,
+                Option<ArrayVec<u8, 64>>,
+                Option<ArrayVec<u8, 64>>,
+                Option<ArrayVec<u8, 64>>,
+            >,
+             destination: &mut Option<MessageSummary>| {
+                // Burns have no recipient; anything other than the two known actions is rejected
+                // rather than guessed at.
+                let is_burn = match o.field_action.as_ref()?.as_slice() {
+                    b"dao_transfer" => false,
+                    b"dao_burn" => true,
+                    _ => return reject(AppSW::InvalidInput),
+                };
+                scroller("Action", |w| {
+                    Ok(write!(w, "{}", if is_burn { "Burn" } else { "Transfer" })?)
+                })?;
+                scroller_paginated("From", |w| {
+                    Ok(write!(
+                        w,
+                        "{}",
+                        from_utf8(o.field_from_address.as_ref().ok_or(ScrollerError)?)?
+                    )?)
+                })?;
+                if !is_burn {
+                    scroller_paginated("To", |w| {
+                        Ok(write!(
+                            w,
+                            "{}",
+                            from_utf8(o.field_to_address.as_ref().ok_or(ScrollerError)?)?
+                        )?)
+                    })?;
+                }
+                scroller("Amount", |w| {
+                    let x = get_amount_in_decimals(o.field_amount.as_ref().ok_or(ScrollerError)?)
+                        .map_err(|_| ScrollerError)?;
+                    Ok(write!(w, "POKT {}", from_utf8(&x)?)?)
+                })?;
+                *destination = Some(MessageSummary {
+                    amount: o.field_amount.clone(),
+                    to_self: false,
+                });
+                Some(())
+            },
+        ),
+    ),
+);
+
+#[cfg(target_os = "nanos")]
+const PARAM_VALUE_SIZE: usize = 128;
+#[cfg(not(target_os = "nanos"))]
+const PARAM_VALUE_SIZE: usize = 256;
+
+type ChangeParamMessageAction = impl JsonInterp<ChangeParamValueSchema, State: Debug>;
+const CHANGE_PARAM_MESSAGE_ACTION: ChangeParamMessageAction = Preaction(
+    || scroller("Change Param", |w| Ok(write!(w, "Governance")?)),
+    Action(
+        ChangeParamValueInterp {
+            field_address: JsonStringAccumulate::<64>,
+            field_param_key: JsonStringAccumulate::<64>,
+            field_param_value: JsonStringAccumulate::<PARAM_VALUE_SIZE>,
+        },
+        #[allow(clippy::type_complexity)]
+        mkfn(
+            |o: &ChangeParamValue<
+                Option<ArrayVec<u8, 64>>,
+                Option<ArrayVec<u8, 64>>,
+                Option<ArrayVec<u8, PARAM_VALUE_SIZE>>,
+            >,
+             destination: &mut Option<()>| {
+                // The value is the amino encoding of the new parameter, which is usually JSON text;
+                // show it as-is when it is printable and fall back to hex otherwise.
+                let value =
+                    base64_decode::<PARAM_VALUE_SIZE>(o.field_param_value.as_ref()?).ok()?;
+                scroller_paginated("Address", |w| {
                     Ok(write!(
                         w,
                         "{}",
-                        from_utf8(o.field_output_address.as_ref().ok_or(ScrollerError)?)?
+                        from_utf8(o.field_address.as_ref().ok_or(ScrollerError)?)?
                     )?)
                 })?;
-                scroller("Service URL", |w| {
+                scroller_paginated("Parameter", |w| {
                     Ok(write!(
                         w,
                         "{}",
-                        from_utf8(o.field_service_url.as_ref().ok_or(ScrollerError)?)?
+                        from_utf8(o.field_param_key.as_ref().ok_or(ScrollerError)?)?
                     )?)
                 })?;
-                for (i, chain) in chains.iter().enumerate() {
-                    let mut buffer: ArrayString<22> = ArrayString::new();
-                    write!(mk_prompt_write(&mut buffer), "Chain ID",).ok()?;
-                    if chains.len() > 1 {
-                        write!(
-                            mk_prompt_write(&mut buffer),
-                            " ({}/{})",
-                            i + 1,
-                            chains.len()
-                        )
-                        .ok()?;
-                    }
-                    scroller(&buffer, |w| {
-                        Ok(write!(w, "{}", from_utf8(chain.as_ref())?)?)
+                if value.iter().all(|c| (b' '..=b'~').contains(c)) {
+                    scroller_paginated("Value", |w| Ok(write!(w, "{}", from_utf8(&value)?)?))?;
+                } else {
+                    scroller_paginated("Value (hex)", |w| {
+                        for byte in value.iter() {
+                            write!(w, "{:02x}", byte)?;
+                        }
+                        Ok(())
                     })?;
                 }
                 *destination = Some(());
@@ -304,25 +879,62 @@ const STAKE_MESSAGE_ACTION: StakeMessageAction = Preaction(
         ),
     ),
 );
-type UnstakeMessageAction = impl JsonInterp<UnstakeValueSchema, State: Debug>;
-const UNSTAKE_MESSAGE_ACTION: UnstakeMessageAction = Preaction(
-    || scroller("Unstake", |w| Ok(write!(w, "POKT")?)),
-    UnstakeValueInterp {
-        field_validator_address: show_address::<"Unstake address">(),
-        field_signer_address: SIGNER_ADDRESS_ACTION,
-    },
-);
 
-type SignerAddressAction = impl JsonInterp<JsonString, State: Debug>;
-const SIGNER_ADDRESS_ACTION: SignerAddressAction =
-    Action(show_address::<"Signer address">(), mkvfn(|_, _| Some(())));
-
-type UnjailMessageAction = impl JsonInterp<UnjailValueSchema, State: Debug>;
-const UNJAIL_MESSAGE_ACTION: UnjailMessageAction = Preaction(
-    || scroller("Unjail", |w| Ok(write!(w, "Transaction")?)),
-    UnjailValueInterp {
+type UpgradeMessageAction = impl JsonInterp<UpgradeValueSchema, State: Debug>;
+const UPGRADE_MESSAGE_ACTION: UpgradeMessageAction = Preaction(
+    || scroller("Upgrade", |w| Ok(write!(w, "Protocol")?)),
+    UpgradeValueInterp {
         field_address: show_address::<"Address">(),
-        field_signer_address: SIGNER_ADDRESS_ACTION,
+        field_upgrade: Action(
+            UpgradeInfoInterp {
+                field_Features: SubInterp(Action(
+                    JsonStringAccumulate::<64>,
+                    mkvfn(|feature: &ArrayVec<u8, 64>, destination| {
+                        scroller("Feature", |w| {
+                            Ok(write!(w, "{}", from_utf8(feature.as_slice())?)?)
+                        })?;
+                        *destination = Some(());
+                        Some(())
+                    }),
+                )),
+                field_Height: JsonStringAccumulate::<32>,
+                field_OldUpgradeHeight: JsonStringAccumulate::<32>,
+                field_Version: JsonStringAccumulate::<32>,
+            },
+            #[allow(clippy::type_complexity)]
+            mkfn(
+                |o: &UpgradeInfo<
+                    Option<()>,
+                    Option<ArrayVec<u8, 32>>,
+                    Option<ArrayVec<u8, 32>>,
+                    Option<ArrayVec<u8, 32>>,
+                >,
+                 destination: &mut Option<()>| {
+                    scroller("Height", |w| {
+                        Ok(write!(
+                            w,
+                            "{}",
+                            from_utf8(o.field_Height.as_ref().ok_or(ScrollerError)?)?
+                        )?)
+                    })?;
+                    scroller("Version", |w| {
+                        Ok(write!(
+                            w,
+                            "{}",
+                            from_utf8(o.field_Version.as_ref().ok_or(ScrollerError)?)?
+                        )?)
+                    })?;
+                    if Settings.expert_mode() {
+                        let old_height = o.field_OldUpgradeHeight.as_ref()?;
+                        scroller_paginated("Old Upgrade Height", |w| {
+                            Ok(write_escaped(w, old_height)?)
+                        })?;
+                    }
+                    *destination = Some(());
+                    Some(())
+                },
+            ),
+        ),
     },
 );
 
@@ -456,37 +1068,278 @@ impl From<CryptographyError> for SignTempError {
     }
 }
 
-// Only one fees field is supported at the moment, so no string -> number conversion/summation required
+// Sum of all the fee entries, in upokt, kept as a decimal string so it isn't limited to a u64.
 #[derive(Clone, Debug)]
-struct TotalFees(pub Option<ArrayVec<u8, 64>>);
+enum TotalFees {
+    Sum(ArrayVec<u8, 64>),
+    // An entry wasn't a number, or the total no longer fits; the transaction is rejected.
+    Invalid,
+}
 
 impl Summable<TotalFees> for TotalFees {
     fn zero() -> Self {
-        TotalFees(None)
+        let mut zero = ArrayVec::new();
+        zero.push(b'0');
+        TotalFees::Sum(zero)
     }
     fn add_and_set(&mut self, other: &TotalFees) {
-        *self = TotalFees(other.0.clone());
+        *self = match (&*self, other) {
+            (TotalFees::Sum(a), TotalFees::Sum(b)) => match add_amounts(a, b) {
+                Ok(sum) => TotalFees::Sum(sum),
+                Err(()) => TotalFees::Invalid,
+            },
+            _ => TotalFees::Invalid,
+        };
     }
 }
 
-static mut SIGNING_ADDRESS: PKH = PKH([0; 20]);
+// pocket-core's MaxMemoCharacters; anything the chain accepts must fit.
+const MEMO_SIZE: usize = 75;
 
-pub type SignImplT = impl InterpParser<DoubledSignParameters, Returning = ArrayVec<u8, 128>>;
+const CHAIN_ID_SIZE: usize = 32;
 
-pub const SIGN_IMPL: SignImplT = WithStackBoxed(DynBind(
-    Action(
-        SubInterp(DefaultInterp),
-        // And ask the user if this is the key the meant to sign with:
-        mktfn(
-            |path: &ArrayVec<u32, 10>, destination, mut ed: DynamicStackBox<Ed25519>| {
-                ed.init(path.clone(), false).ok()?;
-                with_public_keys(path, false, |_, pkh: &PKH| {
-                    unsafe {
-                        SIGNING_ADDRESS.0 = pkh.0;
-                    }
-                    // *destination = Some(ed);
-                    set_from_thunk(destination, || Some(ed)); //  Ed25519::new(path).ok());
-                    Ok::<_, SignTempError>(())
+const ENTROPY_SIZE: usize = 32;
+
+fn network_name(chain_id: &[u8]) -> Option<&'static str> {
+    match chain_id {
+        b"mainnet" => Some("Pocket Mainnet"),
+        b"testnet" => Some("Pocket Testnet"),
+        _ => None,
+    }
+}
+
+// An unfamiliar network is shown by its raw chain ID, and also gets a warning screen, so that a
+// mainnet transfer can't be passed off as a testnet rehearsal or vice versa.
+fn show_chain_id(chain_id: &[u8]) -> Option<()> {
+    match network_name(chain_id) {
+        Some(name) => scroller("Network", |w| Ok(write!(w, "{}", name)?)),
+        None => scroller_paginated("Network", |w| Ok(write_escaped(w, chain_id)?)),
+    }
+}
+
+// Expert mode shows the fields that the normal flow leaves out or summarises; the fee entries,
+// message type, old upgrade height and the fields of an unknown message are shown as they are
+// parsed. The chain ID is already on the Network screen. Unknown keys never get this far, as the
+// struct parsers reject them.
+fn show_expert_fields(entropy: &[u8]) -> Option<()> {
+    scroller_paginated("Entropy", |w| Ok(write_escaped(w, entropy)?))
+}
+
+fn message_summary(msg: &MessageReturnT) -> Option<&MessageSummary> {
+    match msg {
+        MessageReturn::SendMessageReturn(s) => s.as_ref(),
+        MessageReturn::StakeMessageReturn(s) => s.as_ref(),
+        MessageReturn::AppStakeMessageReturn(s) => s.as_ref(),
+        MessageReturn::DaoTransferMessageReturn(s) => s.as_ref(),
+        MessageReturn::LegacyStakeMessageReturn(s) => s.as_ref(),
+        _ => None,
+    }
+}
+
+static mut SIGNING_ADDRESS: PKH = PKH([0; 20]);
+
+// The second pass over a transaction hashes it as well as signing it, so that the user can compare
+// the digest with what their wallet shows. This is the SHA-256 of the JSON sign bytes, not the hash
+// a block explorer shows for the transaction once it is on chain. It follows the signature in the
+// result.
+pub struct TxSigner {
+    ed: DynamicStackBox<Ed25519>,
+    hash: SHA256,
+    len: usize,
+}
+
+pub const TX_HASH_LEN: usize = SHA256::N;
+
+impl Default for TxSigner {
+    fn default() -> Self {
+        TxSigner::new(DynamicStackBox::default())
+    }
+}
+
+impl Debug for TxSigner {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "TxSigner")
+    }
+}
+
+impl TxSigner {
+    fn new(ed: DynamicStackBox<Ed25519>) -> Self {
+        TxSigner {
+            ed,
+            hash: SHA256::new(),
+            len: 0,
+        }
+    }
+
+    fn update(&mut self, bytes: &[u8]) {
+        self.hash.update(bytes);
+        self.ed.update(bytes);
+        self.len += bytes.len();
+    }
+
+    // Shown after the first pass of a blind signature, before anything is signed, so the payload
+    // can be checked against the host at a glance; the full hash is still shown before signing.
+    fn show_payload_digest(&mut self) -> Option<()> {
+        let hash = self.hash.finalize::<Hash<{ SHA256::N }>>();
+        scroller("Payload Hash", |w| {
+            write_hex_groups(w, &hash.0[..PAYLOAD_DIGEST_BYTES])?;
+            write!(w, " ... ")?;
+            write_hex_groups(w, &hash.0[hash.0.len() - PAYLOAD_DIGEST_BYTES..])?;
+            Ok(())
+        })?;
+        scroller("Payload Length", |w| Ok(write!(w, "{} bytes", self.len)?))
+    }
+
+    fn finish(&mut self, prompt: &str, destination: &mut Option<ArrayVec<u8, 128>>) -> Option<()> {
+        let hash = self.hash.finalize::<Hash<{ SHA256::N }>>();
+        scroller_paginated("JSON SHA-256", |w| {
+            for byte in hash.0 {
+                write!(w, "{:02x}", byte)?;
+            }
+            Ok(())
+        })?;
+        final_accept_prompt(&[prompt]).or_else(|| reject(AppSW::UserRejected))?;
+        let sig = self.ed.finalize();
+        *destination = Some(ArrayVec::new());
+        let rv = destination.as_mut()?;
+        rv.try_extend_from_slice(&sig.ok()?.0).ok()?;
+        rv.try_extend_from_slice(&hash.0).ok()
+    }
+}
+
+// Bytes taken from each end of the payload hash for its short form.
+const PAYLOAD_DIGEST_BYTES: usize = 4;
+
+// Hex in groups of two bytes, which is easier to compare by eye than one long run of digits.
+fn write_hex_groups(w: &mut impl Write, bytes: &[u8]) -> core::fmt::Result {
+    for (i, group) in bytes.chunks(2).enumerate() {
+        if i > 0 {
+            write!(w, " ")?;
+        }
+        for byte in group {
+            write!(w, "{:02x}", byte)?;
+        }
+    }
+    Ok(())
+}
+
+type PoktCmdAction = impl JsonInterp<PoktCmdSchema, State: Debug, Returning = ()>;
+// Shared by clear and blind signing; only the latter lets an unknown message type through.
+const fn pokt_cmd_action(allow_unknown_messages: bool) -> PoktCmdAction {
+    Action(
+        PoktCmdInterp {
+            field_chain_id: JsonStringAccumulate::<CHAIN_ID_SIZE>,
+            field_entropy: JsonStringAccumulate::<ENTROPY_SIZE>,
+            field_fee: SubInterpMFold::new(Action(
+                AmountTypeInterp {
+                    field_amount: JsonStringAccumulate::<64>,
+                    field_denom: JsonStringAccumulate::<64>,
+                },
+                mkfnc(
+                    |o: &AmountType<Option<ArrayVec<u8, 64>>, Option<ArrayVec<u8, 64>>>,
+                     destination: &mut Option<TotalFees>,
+                     _| {
+                        if Settings.expert_mode() {
+                            scroller("Fee Entry", |w| {
+                                write_escaped(w, o.field_amount.as_ref().ok_or(ScrollerError)?)?;
+                                write!(w, " ")?;
+                                Ok(write_escaped(
+                                    w,
+                                    o.field_denom.as_ref().ok_or(ScrollerError)?,
+                                )?)
+                            })?;
+                        }
+                        // Every amount is shown as POKT, so a fee in any other denomination
+                        // would be misrepresented; refuse it.
+                        if o.field_denom.as_ref()?.as_slice() != b"upokt" {
+                            return reject(AppSW::InvalidInput);
+                        }
+                        *destination = Some(TotalFees::Sum(o.field_amount.clone()?));
+                        Some(())
+                    },
+                ),
+            )),
+            field_memo: JsonStringAccumulate::<MEMO_SIZE>,
+            field_msg: Message {
+                send_message: SEND_MESSAGE_ACTION,
+                unjail_message: UNJAIL_MESSAGE_ACTION,
+                stake_message: STAKE_MESSAGE_ACTION,
+                unstake_message: UNSTAKE_MESSAGE_ACTION,
+                app_stake_message: APP_STAKE_MESSAGE_ACTION,
+                app_unstake_message: APP_UNSTAKE_MESSAGE_ACTION,
+                app_unjail_message: APP_UNJAIL_MESSAGE_ACTION,
+                dao_transfer_message: DAO_TRANSFER_MESSAGE_ACTION,
+                change_param_message: CHANGE_PARAM_MESSAGE_ACTION,
+                upgrade_message: UPGRADE_MESSAGE_ACTION,
+                legacy_stake_message: LEGACY_STAKE_MESSAGE_ACTION,
+                allow_unknown: allow_unknown_messages,
+            },
+        },
+        #[allow(clippy::type_complexity)]
+        mkfn(
+            |o: &PoktCmd<
+                Option<ArrayVec<u8, CHAIN_ID_SIZE>>,
+                Option<ArrayVec<u8, ENTROPY_SIZE>>,
+                Option<TotalFees>,
+                Option<ArrayVec<u8, MEMO_SIZE>>,
+                Option<MessageReturnT>,
+            >,
+             ret: &mut Option<()>| {
+                match &o.field_fee {
+                    Some(TotalFees::Sum(fee)) => {
+                        scroller("Fee", |w| {
+                            let x = get_amount_in_decimals(fee).map_err(|_| ScrollerError)?;
+                            Ok(write!(w, "POKT {}", from_utf8(&x)?)?)
+                        })?;
+                    }
+                    Some(TotalFees::Invalid) => return reject(AppSW::InvalidInput),
+                    None => {}
+                }
+                match o.field_memo.as_ref() {
+                    Some(memo) if !memo.is_empty() => {
+                        scroller_paginated("Memo", |w| Ok(write_escaped(w, memo)?))?;
+                    }
+                    _ => scroller("No memo", |_w| Ok(()))?,
+                }
+                let chain_id = o.field_chain_id.as_ref()?;
+                show_chain_id(chain_id)?;
+                if Settings.expert_mode() {
+                    show_expert_fields(o.field_entropy.as_ref()?)?;
+                }
+                let fee = match &o.field_fee {
+                    Some(TotalFees::Sum(fee)) => Some(fee.as_slice()),
+                    _ => None,
+                };
+                show_warnings(&collect_warnings(
+                    network_name(chain_id).is_some(),
+                    fee,
+                    o.field_msg.as_ref().and_then(message_summary),
+                ))?;
+                *ret = Some(());
+                Some(())
+            },
+        ),
+    )
+}
+
+pub type SignImplT = impl InterpParser<DoubledSignParameters, Returning = ArrayVec<u8, 128>>;
+
+pub const SIGN_IMPL: SignImplT = WithStackBoxed(DynBind(
+    Action(
+        SubInterp(DefaultInterp),
+        // And ask the user if this is the key the meant to sign with:
+        mktfn(
+            |path: &ArrayVec<u32, 10>, destination, mut ed: DynamicStackBox<Ed25519>| {
+                ed.init(path.clone(), false)
+                    .ok()
+                    .or_else(|| reject(AppSW::BadDerivationPath))?;
+                with_public_keys(path, false, |_, pkh: &PKH| {
+                    unsafe {
+                        SIGNING_ADDRESS.0 = pkh.0;
+                    }
+                    // *destination = Some(ed);
+                    set_from_thunk(destination, || Some(ed)); //  Ed25519::new(path).ok());
+                    Ok::<_, SignTempError>(())
                 })
                 .ok()?;
                 Some(())
@@ -499,59 +1352,7 @@ pub const SIGN_IMPL: SignImplT = WithStackBoxed(DynBind(
                 DynamicStackBox::<Ed25519>::default, // move || edward.clone(),
                 |s: &mut DynamicStackBox<Ed25519>, b: &[u8]| s.update(b),
                 Action(
-                    Json(Action(
-                        PoktCmdInterp {
-                            field_chain_id: DropInterp,
-                            field_entropy: DropInterp,
-                            field_fee: SubInterpMFold::new(Action(
-                                AmountTypeInterp {
-                                    field_amount: JsonStringAccumulate::<64>,
-                                    field_denom: JsonStringAccumulate::<64>,
-                                },
-                                mkfnc(
-                                    |o: &AmountType<
-                                        Option<ArrayVec<u8, 64>>,
-                                        Option<ArrayVec<u8, 64>>,
-                                    >,
-                                     destination: &mut Option<TotalFees>,
-                                     _| {
-                                        *destination = Some(TotalFees(o.field_amount.clone()));
-                                        Some(())
-                                    },
-                                ),
-                            )),
-                            field_memo: DropInterp,
-                            field_msg: Message {
-                                send_message: SEND_MESSAGE_ACTION,
-                                unjail_message: UNJAIL_MESSAGE_ACTION,
-                                stake_message: STAKE_MESSAGE_ACTION,
-                                unstake_message: UNSTAKE_MESSAGE_ACTION,
-                            },
-                        },
-                        #[allow(clippy::type_complexity)]
-                        mkfn(
-                            |o: &PoktCmd<
-                                Option<()>,
-                                Option<()>,
-                                Option<TotalFees>,
-                                Option<()>,
-                                Option<MessageReturnT>,
-                            >,
-                             ret: &mut Option<()>| {
-                                if let Some(fee) = &o.field_fee {
-                                    scroller("Fee", |w| {
-                                        let x = get_amount_in_decimals(
-                                            fee.0.as_ref().ok_or(ScrollerError)?,
-                                        )
-                                        .map_err(|_| ScrollerError)?;
-                                        Ok(write!(w, "POKT {}", from_utf8(&x)?)?)
-                                    })?;
-                                }
-                                *ret = Some(());
-                                Some(())
-                            },
-                        ),
-                    )),
+                    Json(TrackPath(pokt_cmd_action(false))),
                     mkvfn(|_, ret| {
                         *ret = Some(());
                         Some(())
@@ -561,41 +1362,86 @@ pub const SIGN_IMPL: SignImplT = WithStackBoxed(DynBind(
             ),
             mkmvfn(
                 |(_, initial_edward): (Option<()>, DynamicStackBox<Ed25519>),
-                 destination: &mut Option<DynamicStackBox<Ed25519>>|
+                 destination: &mut Option<TxSigner>|
                  -> Option<()> {
-                    *destination = Some(initial_edward);
-                    destination.as_mut()?.done_with_r().ok()?;
+                    *destination = Some(TxSigner::new(initial_edward));
+                    destination.as_mut()?.ed.done_with_r().ok()?;
                     Some(())
                 },
             ),
         ),
         MoveAction(
-            ObserveLengthedBytes(
-                DynamicStackBox::<Ed25519>::default, // move || edward.clone(),
-                |s: &mut DynamicStackBox<Ed25519>, b: &[u8]| s.update(b),
-                /*  || Ed25519::default(), // move || edward.clone(),
-                Ed25519::update,*/
-                Json(DropInterp),
-                true,
-            ),
+            ObserveLengthedBytes(TxSigner::default, TxSigner::update, Json(DropInterp), true),
             mkmvfn(
-                |(_, mut final_edward): (_, DynamicStackBox<Ed25519>),
-                 destination: &mut Option<ArrayVec<u8, 128>>| {
-                    final_accept_prompt(&["Sign Transaction?"])?;
-                    // let mut final_edward_copy = final_edward.clone();
-                    let sig = final_edward.finalize();
-                    *destination = Some(ArrayVec::new());
-                    destination
-                        .as_mut()?
-                        .try_extend_from_slice(&sig.ok()?.0)
-                        .ok()?;
-                    Some(())
+                |(_, mut signer): (_, TxSigner), destination: &mut Option<ArrayVec<u8, 128>>| {
+                    signer.finish("Sign Transaction?", destination)
                 },
             ),
         ),
     ),
 ));
 
+// Blind signing still shows whatever it can decode: each token goes both to the structured PoktCmd
+// parser and to a parser that accepts any JSON. If the structured parse gives up, the rest is only
+// covered by the transaction hash, and the user is told so once the whole value has been read.
+#[derive(Debug)]
+pub struct BestEffort<I>(pub I);
+
+#[derive(Debug)]
+pub struct BestEffortState<S, R> {
+    any: <DropInterp as ParserCommon<JsonAny>>::State,
+    decoding: Option<S>,
+    decoded: Option<R>,
+}
+
+impl<I: JsonInterp<PoktCmdSchema>> ParserCommon<JsonAny> for BestEffort<I> {
+    type State = BestEffortState<
+        <I as ParserCommon<PoktCmdSchema>>::State,
+        <I as ParserCommon<PoktCmdSchema>>::Returning,
+    >;
+    type Returning = ();
+    fn init(&self) -> Self::State {
+        BestEffortState {
+            any: <DropInterp as ParserCommon<JsonAny>>::init(&DropInterp),
+            decoding: Some(self.0.init()),
+            decoded: None,
+        }
+    }
+}
+
+impl<I: JsonInterp<PoktCmdSchema>> JsonInterp<JsonAny> for BestEffort<I> {
+    #[inline(never)]
+    fn parse(
+        &self,
+        state: &mut Self::State,
+        token: JsonToken<'_>,
+        destination: &mut Option<Self::Returning>,
+    ) -> Result<(), Option<OOB>> {
+        if let Some(decoding) = state.decoding.as_mut() {
+            match self.0.parse(decoding, token, &mut state.decoded) {
+                Err(None) => {}
+                // What the structured parse refused doesn't reject the transaction, so it mustn't
+                // be reported if something else does.
+                Err(Some(_)) => {
+                    state.decoding = None;
+                    clear_rejection();
+                }
+                Ok(()) => state.decoding = None,
+            }
+        }
+        <DropInterp as JsonInterp<JsonAny>>::parse(
+            &DropInterp,
+            &mut state.any,
+            token,
+            destination,
+        )?;
+        if state.decoded.is_none() {
+            show_warning(Warning::NotFullyDecoded).ok_or(Some(OOB::Reject))?;
+        }
+        Ok(())
+    }
+}
+
 pub type BlindSignImplT =
     impl InterpParser<DoubledBlindSignParameters, Returning = ArrayVec<u8, 128_usize>>;
 
@@ -611,14 +1457,18 @@ pub static BLIND_SIGN_IMPL: BlindSignImplT = Preaction(
             // And ask the user if this is the key the meant to sign with:
             mktfn(
                 |path: &ArrayVec<u32, 10>, destination, mut ed: DynamicStackBox<Ed25519>| {
-                    ed.init(path.clone(), false).ok()?;
+                    ed.init(path.clone(), false)
+                        .ok()
+                        .or_else(|| reject(AppSW::BadDerivationPath))?;
                     with_public_keys(path, false, |_, pkh: &PKH| {
+                        unsafe {
+                            SIGNING_ADDRESS.0 = pkh.0;
+                        }
                         try_option(|| -> Option<()> {
                             scroller("Sign for Address", |w| Ok(write!(w, "{pkh}")?))?;
                             Some(())
                         }())?;
-                        // *destination = Some(ed);
-                        set_from_thunk(destination, || Some(ed)); //  Ed25519::new(path).ok());
+                        set_from_thunk(destination, || Some(TxSigner::new(ed)));
                         Ok::<_, SignTempError>(())
                     })
                     .ok()?;
@@ -629,41 +1479,30 @@ pub static BLIND_SIGN_IMPL: BlindSignImplT = Preaction(
         DynBind(
             MoveAction(
                 ObserveLengthedBytes(
-                    DynamicStackBox::<Ed25519>::default, // move || edward.clone(),
-                    |s: &mut DynamicStackBox<Ed25519>, b: &[u8]| s.update(b),
-                    Json(DropInterp),
+                    TxSigner::default,
+                    TxSigner::update,
+                    Json(TrackPath(BestEffort(pokt_cmd_action(true)))),
                     true,
                 ),
                 mkmvfn(
-                    |(_, initial_edward): (Option<()>, DynamicStackBox<Ed25519>),
-                     destination: &mut Option<DynamicStackBox<Ed25519>>|
+                    |(_, mut initial): (Option<()>, TxSigner),
+                     destination: &mut Option<TxSigner>|
                      -> Option<()> {
-                        *destination = Some(initial_edward);
-                        destination.as_mut()?.done_with_r().ok()?;
+                        initial.show_payload_digest()?;
+                        *destination = Some(TxSigner::new(initial.ed));
+                        destination.as_mut()?.ed.done_with_r().ok()?;
                         Some(())
                     },
                 ),
             ),
             MoveAction(
-                ObserveLengthedBytes(
-                    DynamicStackBox::<Ed25519>::default, // move || edward.clone(),
-                    |s: &mut DynamicStackBox<Ed25519>, b: &[u8]| s.update(b),
-                    /*  || Ed25519::default(), // move || edward.clone(),
-                    Ed25519::update,*/
-                    Json(DropInterp),
-                    true,
-                ),
+                ObserveLengthedBytes(TxSigner::default, TxSigner::update, Json(DropInterp), true),
                 mkmvfn(
-                    |(_, mut final_edward): (_, DynamicStackBox<Ed25519>),
-                     destination: &mut Option<ArrayVec<u8, 128>>| {
-                        final_accept_prompt(&["Blind Sign Transaction?"])?;
-                        // let mut final_edward_copy = final_edward.clone();
-                        let sig = final_edward.finalize();
-                        *destination = Some(ArrayVec::new());
-                        destination
-                            .as_mut()?
-                            .try_extend_from_slice(&sig.ok()?.0)
-                            .ok()?;
+                    |(_, mut signer): (_, TxSigner),
+                     destination: &mut Option<ArrayVec<u8, 128>>|
+                     -> Option<()> {
+                        signer.finish("Blind Sign Transaction?", destination)?;
+                        Settings.blind_signature_made();
                         Some(())
                     },
                 ),
@@ -677,11 +1516,14 @@ pub static BLIND_SIGN_IMPL: BlindSignImplT = Preaction(
 
 #[derive(InPlaceInit)]
 #[repr(u8)]
-pub enum ParsersStateInner<A, B, C> {
+pub enum ParsersStateInner<A, B, C, D> {
     NoState,
     GetAddressState(A),
     SignState(B),
     BlindSignState(C),
+    GetPubkeysState(D),
+    // A finished command's result, while it is sent to the host a piece at a time.
+    ResultState(ArrayVec<u8, MAX_RESULT_SIZE>),
     /*GetAddressState(<GetAddressImplT as ParserCommon<Bip32Key>>::State),
     SignState(<SignImplT as ParserCommon<DoubledSignParameters>>::State),*/
 }
@@ -690,10 +1532,23 @@ pub type ParsersState = ParsersStateInner<
     <GetAddressImplT as ParserCommon<Bip32Key>>::State,
     <SignImplT as ParserCommon<DoubledSignParameters>>::State,
     <BlindSignImplT as ParserCommon<DoubledBlindSignParameters>>::State,
+    <GetPubkeysImplT as ParserCommon<Bip32Keys>>::State,
 >;
 
 pub fn reset_parsers_state(state: &mut ParsersState) {
     *state = ParsersState::NoState;
+    clear_rejection();
+}
+
+// The parser's state isn't needed once it has finished, so its result takes its place.
+pub fn set_result_state(state: &mut ParsersState, result: &[u8]) -> Option<()> {
+    *state = ParsersState::ResultState(ArrayVec::new());
+    match state {
+        ParsersState::ResultState(ref mut r) => r.try_extend_from_slice(result).ok(),
+        _ => {
+            unreachable!("Should be impossible because assignment right above")
+        }
+    }
 }
 
 /*
@@ -711,6 +1566,14 @@ unjail_value_definition! {}
 public_key_definition! {}
 stake_value_definition! {}
 unstake_value_definition! {}
+app_stake_value_definition! {}
+app_unstake_value_definition! {}
+app_unjail_value_definition! {}
+dao_transfer_value_definition! {}
+change_param_value_definition! {}
+upgrade_info_definition! {}
+upgrade_value_definition! {}
+legacy_stake_value_definition! {}
 
 #[derive(Copy, Clone, Debug)]
 pub enum MessageType {
@@ -718,6 +1581,14 @@ pub enum MessageType {
     UnjailMessage,
     StakeMessage,
     UnstakeMessage,
+    AppStakeMessage,
+    AppUnstakeMessage,
+    AppUnjailMessage,
+    DaoTransferMessage,
+    ChangeParamMessage,
+    UpgradeMessage,
+    LegacyStakeMessage,
+    UnknownMessage,
 }
 
 #[derive(Debug)]
@@ -726,11 +1597,28 @@ pub struct Message<
     UnjailInterp: JsonInterp<UnjailValueSchema>,
     StakeInterp: JsonInterp<StakeValueSchema>,
     UnstakeInterp: JsonInterp<UnstakeValueSchema>,
+    AppStakeInterp: JsonInterp<AppStakeValueSchema>,
+    AppUnstakeInterp: JsonInterp<AppUnstakeValueSchema>,
+    AppUnjailInterp: JsonInterp<AppUnjailValueSchema>,
+    DaoTransferInterp: JsonInterp<DaoTransferValueSchema>,
+    ChangeParamInterp: JsonInterp<ChangeParamValueSchema>,
+    UpgradeInterp: JsonInterp<UpgradeValueSchema>,
+    LegacyStakeInterp: JsonInterp<LegacyStakeValueSchema>,
 > {
     pub send_message: SendInterp,
     pub unjail_message: UnjailInterp,
     pub stake_message: StakeInterp,
     pub unstake_message: UnstakeInterp,
+    pub app_stake_message: AppStakeInterp,
+    pub app_unstake_message: AppUnstakeInterp,
+    pub app_unjail_message: AppUnjailInterp,
+    pub dao_transfer_message: DaoTransferInterp,
+    pub change_param_message: ChangeParamInterp,
+    pub upgrade_message: UpgradeInterp,
+    pub legacy_stake_message: LegacyStakeInterp,
+    // Blind signing still wants the rest of the transaction decoded when the message type isn't
+    // one we know; clear signing rejects such messages.
+    pub allow_unknown: bool,
 }
 
 type TemporaryStringState<const N: usize> =
@@ -739,8 +1627,19 @@ type TemporaryStringReturn<const N: usize> =
     Option<<JsonStringAccumulate<N> as ParserCommon<JsonString>>::Returning>;
 
 #[derive(Debug)]
-pub enum MessageState<SendMessageState, UnjailMessageState, StakeMessageState, UnstakeMessageState>
-{
+pub enum MessageState<
+    SendMessageState,
+    UnjailMessageState,
+    StakeMessageState,
+    UnstakeMessageState,
+    AppStakeMessageState,
+    AppUnstakeMessageState,
+    AppUnjailMessageState,
+    DaoTransferMessageState,
+    ChangeParamMessageState,
+    UpgradeMessageState,
+    LegacyStakeMessageState,
+> {
     Start,
     TypeLabel(TemporaryStringState<4>, TemporaryStringReturn<4>),
     KeySep1,
@@ -756,9 +1655,26 @@ pub enum MessageState<SendMessageState, UnjailMessageState, StakeMessageState, U
     UnjailMessageState(UnjailMessageState),
     StakeMessageState(StakeMessageState),
     UnstakeMessageState(UnstakeMessageState),
+    AppStakeMessageState(AppStakeMessageState),
+    AppUnstakeMessageState(AppUnstakeMessageState),
+    AppUnjailMessageState(AppUnjailMessageState),
+    DaoTransferMessageState(DaoTransferMessageState),
+    ChangeParamMessageState(ChangeParamMessageState),
+    UpgradeMessageState(UpgradeMessageState),
+    LegacyStakeMessageState(LegacyStakeMessageState),
+    UnknownMessageState(
+        <DropInterp as ParserCommon<JsonAny>>::State,
+        Option<UnknownFields>,
+    ),
     End,
 }
 
+// Pre-8.0 message names are still accepted, but flagged so the user knows the transaction was
+// built by older tooling.
+fn show_legacy_notice() -> Result<(), Option<OOB>> {
+    scroller("Legacy format", |w| Ok(write!(w, "Pre-8.0 message")?)).ok_or(Some(OOB::Reject))
+}
+
 fn init_str<const N: usize>() -> <JsonStringAccumulate<N> as ParserCommon<JsonString>>::State {
     <JsonStringAccumulate<N> as ParserCommon<JsonString>>::init(&JsonStringAccumulate)
 }
@@ -780,17 +1696,39 @@ pub enum MessageReturn<
     UnjailMessageReturn,
     StakeMessageReturn,
     UnstakeMessageReturn,
+    AppStakeMessageReturn,
+    AppUnstakeMessageReturn,
+    AppUnjailMessageReturn,
+    DaoTransferMessageReturn,
+    ChangeParamMessageReturn,
+    UpgradeMessageReturn,
+    LegacyStakeMessageReturn,
 > {
     SendMessageReturn(Option<SendMessageReturn>),
     UnjailMessageReturn(Option<UnjailMessageReturn>),
     StakeMessageReturn(Option<StakeMessageReturn>),
     UnstakeMessageReturn(Option<UnstakeMessageReturn>),
+    AppStakeMessageReturn(Option<AppStakeMessageReturn>),
+    AppUnstakeMessageReturn(Option<AppUnstakeMessageReturn>),
+    AppUnjailMessageReturn(Option<AppUnjailMessageReturn>),
+    DaoTransferMessageReturn(Option<DaoTransferMessageReturn>),
+    ChangeParamMessageReturn(Option<ChangeParamMessageReturn>),
+    UpgradeMessageReturn(Option<UpgradeMessageReturn>),
+    LegacyStakeMessageReturn(Option<LegacyStakeMessageReturn>),
+    UnknownMessageReturn(Option<()>),
 }
 type MessageReturnT = MessageReturn<
     <SendMessageAction as ParserCommon<SendValueSchema>>::Returning,
     <UnjailMessageAction as ParserCommon<UnjailValueSchema>>::Returning,
     <StakeMessageAction as ParserCommon<StakeValueSchema>>::Returning,
     <UnstakeMessageAction as ParserCommon<UnstakeValueSchema>>::Returning,
+    <AppStakeMessageAction as ParserCommon<AppStakeValueSchema>>::Returning,
+    <AppUnstakeMessageAction as ParserCommon<AppUnstakeValueSchema>>::Returning,
+    <AppUnjailMessageAction as ParserCommon<AppUnjailValueSchema>>::Returning,
+    <DaoTransferMessageAction as ParserCommon<DaoTransferValueSchema>>::Returning,
+    <ChangeParamMessageAction as ParserCommon<ChangeParamValueSchema>>::Returning,
+    <UpgradeMessageAction as ParserCommon<UpgradeValueSchema>>::Returning,
+    <LegacyStakeMessageAction as ParserCommon<LegacyStakeValueSchema>>::Returning,
 >;
 
 impl ParserCommon<MessageSchema> for DropInterp {
@@ -817,24 +1755,65 @@ impl<
         UnjailInterp: JsonInterp<UnjailValueSchema>,
         StakeInterp: JsonInterp<StakeValueSchema>,
         UnstakeInterp: JsonInterp<UnstakeValueSchema>,
-    > ParserCommon<MessageSchema> for Message<SendInterp, UnjailInterp, StakeInterp, UnstakeInterp>
+        AppStakeInterp: JsonInterp<AppStakeValueSchema>,
+        AppUnstakeInterp: JsonInterp<AppUnstakeValueSchema>,
+        AppUnjailInterp: JsonInterp<AppUnjailValueSchema>,
+        DaoTransferInterp: JsonInterp<DaoTransferValueSchema>,
+        ChangeParamInterp: JsonInterp<ChangeParamValueSchema>,
+        UpgradeInterp: JsonInterp<UpgradeValueSchema>,
+        LegacyStakeInterp: JsonInterp<LegacyStakeValueSchema>,
+    > ParserCommon<MessageSchema>
+    for Message<
+        SendInterp,
+        UnjailInterp,
+        StakeInterp,
+        UnstakeInterp,
+        AppStakeInterp,
+        AppUnstakeInterp,
+        AppUnjailInterp,
+        DaoTransferInterp,
+        ChangeParamInterp,
+        UpgradeInterp,
+        LegacyStakeInterp,
+    >
 where
     <SendInterp as ParserCommon<SendValueSchema>>::State: core::fmt::Debug,
     <UnjailInterp as ParserCommon<UnjailValueSchema>>::State: core::fmt::Debug,
     <StakeInterp as ParserCommon<StakeValueSchema>>::State: core::fmt::Debug,
     <UnstakeInterp as ParserCommon<UnstakeValueSchema>>::State: core::fmt::Debug,
+    <AppStakeInterp as ParserCommon<AppStakeValueSchema>>::State: core::fmt::Debug,
+    <AppUnstakeInterp as ParserCommon<AppUnstakeValueSchema>>::State: core::fmt::Debug,
+    <AppUnjailInterp as ParserCommon<AppUnjailValueSchema>>::State: core::fmt::Debug,
+    <DaoTransferInterp as ParserCommon<DaoTransferValueSchema>>::State: core::fmt::Debug,
+    <ChangeParamInterp as ParserCommon<ChangeParamValueSchema>>::State: core::fmt::Debug,
+    <UpgradeInterp as ParserCommon<UpgradeValueSchema>>::State: core::fmt::Debug,
+    <LegacyStakeInterp as ParserCommon<LegacyStakeValueSchema>>::State: core::fmt::Debug,
 {
     type State = MessageState<
         <SendInterp as ParserCommon<SendValueSchema>>::State,
         <UnjailInterp as ParserCommon<UnjailValueSchema>>::State,
         <StakeInterp as ParserCommon<StakeValueSchema>>::State,
         <UnstakeInterp as ParserCommon<UnstakeValueSchema>>::State,
+        <AppStakeInterp as ParserCommon<AppStakeValueSchema>>::State,
+        <AppUnstakeInterp as ParserCommon<AppUnstakeValueSchema>>::State,
+        <AppUnjailInterp as ParserCommon<AppUnjailValueSchema>>::State,
+        <DaoTransferInterp as ParserCommon<DaoTransferValueSchema>>::State,
+        <ChangeParamInterp as ParserCommon<ChangeParamValueSchema>>::State,
+        <UpgradeInterp as ParserCommon<UpgradeValueSchema>>::State,
+        <LegacyStakeInterp as ParserCommon<LegacyStakeValueSchema>>::State,
     >;
     type Returning = MessageReturn<
         <SendInterp as ParserCommon<SendValueSchema>>::Returning,
         <UnjailInterp as ParserCommon<UnjailValueSchema>>::Returning,
         <StakeInterp as ParserCommon<StakeValueSchema>>::Returning,
         <UnstakeInterp as ParserCommon<UnstakeValueSchema>>::Returning,
+        <AppStakeInterp as ParserCommon<AppStakeValueSchema>>::Returning,
+        <AppUnstakeInterp as ParserCommon<AppUnstakeValueSchema>>::Returning,
+        <AppUnjailInterp as ParserCommon<AppUnjailValueSchema>>::Returning,
+        <DaoTransferInterp as ParserCommon<DaoTransferValueSchema>>::Returning,
+        <ChangeParamInterp as ParserCommon<ChangeParamValueSchema>>::Returning,
+        <UpgradeInterp as ParserCommon<UpgradeValueSchema>>::Returning,
+        <LegacyStakeInterp as ParserCommon<LegacyStakeValueSchema>>::Returning,
     >;
     fn init(&self) -> Self::State {
         MessageState::Start
@@ -846,12 +1825,39 @@ impl<
         UnjailInterp: JsonInterp<UnjailValueSchema>,
         StakeInterp: JsonInterp<StakeValueSchema>,
         UnstakeInterp: JsonInterp<UnstakeValueSchema>,
-    > JsonInterp<MessageSchema> for Message<SendInterp, UnjailInterp, StakeInterp, UnstakeInterp>
+        AppStakeInterp: JsonInterp<AppStakeValueSchema>,
+        AppUnstakeInterp: JsonInterp<AppUnstakeValueSchema>,
+        AppUnjailInterp: JsonInterp<AppUnjailValueSchema>,
+        DaoTransferInterp: JsonInterp<DaoTransferValueSchema>,
+        ChangeParamInterp: JsonInterp<ChangeParamValueSchema>,
+        UpgradeInterp: JsonInterp<UpgradeValueSchema>,
+        LegacyStakeInterp: JsonInterp<LegacyStakeValueSchema>,
+    > JsonInterp<MessageSchema>
+    for Message<
+        SendInterp,
+        UnjailInterp,
+        StakeInterp,
+        UnstakeInterp,
+        AppStakeInterp,
+        AppUnstakeInterp,
+        AppUnjailInterp,
+        DaoTransferInterp,
+        ChangeParamInterp,
+        UpgradeInterp,
+        LegacyStakeInterp,
+    >
 where
     <SendInterp as ParserCommon<SendValueSchema>>::State: core::fmt::Debug,
     <UnjailInterp as ParserCommon<UnjailValueSchema>>::State: core::fmt::Debug,
     <StakeInterp as ParserCommon<StakeValueSchema>>::State: core::fmt::Debug,
     <UnstakeInterp as ParserCommon<UnstakeValueSchema>>::State: core::fmt::Debug,
+    <AppStakeInterp as ParserCommon<AppStakeValueSchema>>::State: core::fmt::Debug,
+    <AppUnstakeInterp as ParserCommon<AppUnstakeValueSchema>>::State: core::fmt::Debug,
+    <AppUnjailInterp as ParserCommon<AppUnjailValueSchema>>::State: core::fmt::Debug,
+    <DaoTransferInterp as ParserCommon<DaoTransferValueSchema>>::State: core::fmt::Debug,
+    <ChangeParamInterp as ParserCommon<ChangeParamValueSchema>>::State: core::fmt::Debug,
+    <UpgradeInterp as ParserCommon<UpgradeValueSchema>>::State: core::fmt::Debug,
+    <LegacyStakeInterp as ParserCommon<LegacyStakeValueSchema>>::State: core::fmt::Debug,
 {
     #[inline(never)]
     fn parse(
@@ -882,6 +1888,11 @@ where
             }
             MessageState::Type(ref mut temp_string_state, ref mut temp_string_return) => {
                 call_str::<64>(temp_string_state, token, temp_string_return)?;
+                if Settings.expert_mode() {
+                    let msg_type = temp_string_return.as_ref().expect("should be set by now");
+                    scroller_paginated("Message Type", |w| Ok(write_escaped(w, msg_type)?))
+                        .ok_or(Some(OOB::Reject))?;
+                }
                 match temp_string_return
                     .as_ref()
                     .expect("should be set by now")
@@ -903,7 +1914,66 @@ where
                             MessageState::ValueSep(MessageType::UnstakeMessage)
                         });
                     }
-                    _ => return Err(Some(OOB::Reject)),
+                    b"pos/MsgSend" => {
+                        show_legacy_notice()?;
+                        set_from_thunk(state, || MessageState::ValueSep(MessageType::SendMessage));
+                    }
+                    b"pos/MsgUnjail" => {
+                        show_legacy_notice()?;
+                        set_from_thunk(state, || {
+                            MessageState::ValueSep(MessageType::UnjailMessage)
+                        });
+                    }
+                    b"pos/MsgBeginUnstake" => {
+                        show_legacy_notice()?;
+                        set_from_thunk(state, || {
+                            MessageState::ValueSep(MessageType::UnstakeMessage)
+                        });
+                    }
+                    b"apps/MsgAppStake" => {
+                        set_from_thunk(state, || {
+                            MessageState::ValueSep(MessageType::AppStakeMessage)
+                        });
+                    }
+                    b"apps/MsgAppBeginUnstake" => {
+                        set_from_thunk(state, || {
+                            MessageState::ValueSep(MessageType::AppUnstakeMessage)
+                        });
+                    }
+                    b"apps/MsgAppUnjail" => {
+                        set_from_thunk(state, || {
+                            MessageState::ValueSep(MessageType::AppUnjailMessage)
+                        });
+                    }
+                    b"gov/msg_dao_transfer" => {
+                        set_from_thunk(state, || {
+                            MessageState::ValueSep(MessageType::DaoTransferMessage)
+                        });
+                    }
+                    b"gov/msg_change_param" => {
+                        set_from_thunk(state, || {
+                            MessageState::ValueSep(MessageType::ChangeParamMessage)
+                        });
+                    }
+                    b"gov/msg_upgrade" => {
+                        set_from_thunk(state, || {
+                            MessageState::ValueSep(MessageType::UpgradeMessage)
+                        });
+                    }
+                    b"pos/MsgStake" => {
+                        show_legacy_notice()?;
+                        set_from_thunk(state, || {
+                            MessageState::ValueSep(MessageType::LegacyStakeMessage)
+                        });
+                    }
+                    msg_type if self.allow_unknown => {
+                        scroller_paginated("Unknown Message", |w| Ok(write_escaped(w, msg_type)?))
+                            .ok_or(Some(OOB::Reject))?;
+                        set_from_thunk(state, || {
+                            MessageState::ValueSep(MessageType::UnknownMessage)
+                        });
+                    }
+                    _ => return reject(AppSW::UnsupportedMessageType).ok_or(Some(OOB::Reject)),
                 }
             }
             MessageState::ValueSep(msg_type) if token == JsonToken::ValueSeparator => {
@@ -952,6 +2022,57 @@ where
                             MessageState::UnstakeMessageState(self.unstake_message.init())
                         });
                     }
+                    MessageType::AppStakeMessage => {
+                        *destination = Some(MessageReturn::AppStakeMessageReturn(None));
+                        set_from_thunk(state, || {
+                            MessageState::AppStakeMessageState(self.app_stake_message.init())
+                        });
+                    }
+                    MessageType::AppUnstakeMessage => {
+                        *destination = Some(MessageReturn::AppUnstakeMessageReturn(None));
+                        set_from_thunk(state, || {
+                            MessageState::AppUnstakeMessageState(self.app_unstake_message.init())
+                        });
+                    }
+                    MessageType::AppUnjailMessage => {
+                        *destination = Some(MessageReturn::AppUnjailMessageReturn(None));
+                        set_from_thunk(state, || {
+                            MessageState::AppUnjailMessageState(self.app_unjail_message.init())
+                        });
+                    }
+                    MessageType::DaoTransferMessage => {
+                        *destination = Some(MessageReturn::DaoTransferMessageReturn(None));
+                        set_from_thunk(state, || {
+                            MessageState::DaoTransferMessageState(self.dao_transfer_message.init())
+                        });
+                    }
+                    MessageType::ChangeParamMessage => {
+                        *destination = Some(MessageReturn::ChangeParamMessageReturn(None));
+                        set_from_thunk(state, || {
+                            MessageState::ChangeParamMessageState(self.change_param_message.init())
+                        });
+                    }
+                    MessageType::UpgradeMessage => {
+                        *destination = Some(MessageReturn::UpgradeMessageReturn(None));
+                        set_from_thunk(state, || {
+                            MessageState::UpgradeMessageState(self.upgrade_message.init())
+                        });
+                    }
+                    MessageType::LegacyStakeMessage => {
+                        *destination = Some(MessageReturn::LegacyStakeMessageReturn(None));
+                        set_from_thunk(state, || {
+                            MessageState::LegacyStakeMessageState(self.legacy_stake_message.init())
+                        });
+                    }
+                    MessageType::UnknownMessage => {
+                        *destination = Some(MessageReturn::UnknownMessageReturn(None));
+                        set_from_thunk(state, || {
+                            MessageState::UnknownMessageState(
+                                <DropInterp as ParserCommon<JsonAny>>::init(&DropInterp),
+                                Settings.expert_mode().then(UnknownFields::new),
+                            )
+                        });
+                    }
                 }
             }
             MessageState::SendMessageState(ref mut send_message_state) => {
@@ -1007,6 +2128,125 @@ where
                     _ => return Err(Some(OOB::Reject)),
                 }
             }
+            MessageState::AppStakeMessageState(ref mut app_stake_message_state) => {
+                let sub_destination = &mut destination.as_mut().ok_or(Some(OOB::Reject))?;
+                match sub_destination {
+                    MessageReturn::AppStakeMessageReturn(app_stake_message_return) => {
+                        self.app_stake_message.parse(
+                            app_stake_message_state,
+                            token,
+                            app_stake_message_return,
+                        )?;
+                        set_from_thunk(state, || MessageState::End);
+                    }
+                    _ => return Err(Some(OOB::Reject)),
+                }
+            }
+            MessageState::AppUnstakeMessageState(ref mut app_unstake_message_state) => {
+                let sub_destination = &mut destination.as_mut().ok_or(Some(OOB::Reject))?;
+                match sub_destination {
+                    MessageReturn::AppUnstakeMessageReturn(app_unstake_message_return) => {
+                        self.app_unstake_message.parse(
+                            app_unstake_message_state,
+                            token,
+                            app_unstake_message_return,
+                        )?;
+                        set_from_thunk(state, || MessageState::End);
+                    }
+                    _ => return Err(Some(OOB::Reject)),
+                }
+            }
+            MessageState::AppUnjailMessageState(ref mut app_unjail_message_state) => {
+                let sub_destination = &mut destination.as_mut().ok_or(Some(OOB::Reject))?;
+                match sub_destination {
+                    MessageReturn::AppUnjailMessageReturn(app_unjail_message_return) => {
+                        self.app_unjail_message.parse(
+                            app_unjail_message_state,
+                            token,
+                            app_unjail_message_return,
+                        )?;
+                        set_from_thunk(state, || MessageState::End);
+                    }
+                    _ => return Err(Some(OOB::Reject)),
+                }
+            }
+            MessageState::DaoTransferMessageState(ref mut dao_transfer_message_state) => {
+                let sub_destination = &mut destination.as_mut().ok_or(Some(OOB::Reject))?;
+                match sub_destination {
+                    MessageReturn::DaoTransferMessageReturn(dao_transfer_message_return) => {
+                        self.dao_transfer_message.parse(
+                            dao_transfer_message_state,
+                            token,
+                            dao_transfer_message_return,
+                        )?;
+                        set_from_thunk(state, || MessageState::End);
+                    }
+                    _ => return Err(Some(OOB::Reject)),
+                }
+            }
+            MessageState::ChangeParamMessageState(ref mut change_param_message_state) => {
+                let sub_destination = &mut destination.as_mut().ok_or(Some(OOB::Reject))?;
+                match sub_destination {
+                    MessageReturn::ChangeParamMessageReturn(change_param_message_return) => {
+                        self.change_param_message.parse(
+                            change_param_message_state,
+                            token,
+                            change_param_message_return,
+                        )?;
+                        set_from_thunk(state, || MessageState::End);
+                    }
+                    _ => return Err(Some(OOB::Reject)),
+                }
+            }
+            MessageState::UpgradeMessageState(ref mut upgrade_message_state) => {
+                let sub_destination = &mut destination.as_mut().ok_or(Some(OOB::Reject))?;
+                match sub_destination {
+                    MessageReturn::UpgradeMessageReturn(upgrade_message_return) => {
+                        self.upgrade_message.parse(
+                            upgrade_message_state,
+                            token,
+                            upgrade_message_return,
+                        )?;
+                        set_from_thunk(state, || MessageState::End);
+                    }
+                    _ => return Err(Some(OOB::Reject)),
+                }
+            }
+            MessageState::LegacyStakeMessageState(ref mut legacy_stake_message_state) => {
+                let sub_destination = &mut destination.as_mut().ok_or(Some(OOB::Reject))?;
+                match sub_destination {
+                    MessageReturn::LegacyStakeMessageReturn(legacy_stake_message_return) => {
+                        self.legacy_stake_message.parse(
+                            legacy_stake_message_state,
+                            token,
+                            legacy_stake_message_return,
+                        )?;
+                        set_from_thunk(state, || MessageState::End);
+                    }
+                    _ => return Err(Some(OOB::Reject)),
+                }
+            }
+            MessageState::UnknownMessageState(
+                ref mut unknown_message_state,
+                ref mut unknown_fields,
+            ) => {
+                let sub_destination = &mut destination.as_mut().ok_or(Some(OOB::Reject))?;
+                match sub_destination {
+                    MessageReturn::UnknownMessageReturn(unknown_message_return) => {
+                        if let Some(fields) = unknown_fields {
+                            fields.parse(token).ok_or(Some(OOB::Reject))?;
+                        }
+                        <DropInterp as JsonInterp<JsonAny>>::parse(
+                            &DropInterp,
+                            unknown_message_state,
+                            token,
+                            unknown_message_return,
+                        )?;
+                        set_from_thunk(state, || MessageState::End);
+                    }
+                    _ => return Err(Some(OOB::Reject)),
+                }
+            }
             MessageState::End if token == JsonToken::EndObject => return Ok(()),
             _ => return Err(Some(OOB::Reject)),
         };
@@ -1014,6 +2254,285 @@ where
     }
 }
 
+// In expert mode, the fields of a message we can't decode are shown as they go past, each value
+// labelled with the key it was found under. Nested keys aren't qualified by their parents; this
+// is only meant to let an expert see what they are blind signing.
+const UNKNOWN_KEY_SIZE: usize = 32;
+const UNKNOWN_VALUE_SIZE: usize = 64;
+
+#[derive(Debug)]
+pub enum UnknownValue {
+    None,
+    String(
+        TemporaryStringState<UNKNOWN_VALUE_SIZE>,
+        TemporaryStringReturn<UNKNOWN_VALUE_SIZE>,
+    ),
+    Number(
+        TemporaryNumberState<UNKNOWN_VALUE_SIZE>,
+        TemporaryNumberReturn<UNKNOWN_VALUE_SIZE>,
+    ),
+    // A string is only known to be a value, rather than a key, once the next token arrives.
+    Ended(ArrayVec<u8, UNKNOWN_VALUE_SIZE>),
+    TooLong,
+}
+
+#[derive(Debug)]
+pub struct UnknownFields {
+    key: ArrayVec<u8, UNKNOWN_KEY_SIZE>,
+    value: UnknownValue,
+}
+
+impl UnknownFields {
+    fn new() -> Self {
+        UnknownFields {
+            key: ArrayVec::new(),
+            value: UnknownValue::None,
+        }
+    }
+
+    fn show(&self, value: Option<&[u8]>) -> Option<()> {
+        let key = &self.key;
+        scroller_paginated("Unknown Field", |w| {
+            write_escaped(w, key)?;
+            write!(w, ": ")?;
+            match value {
+                Some(value) => Ok(write_escaped(w, value)?),
+                None => Ok(write!(w, "(too long to show)")?),
+            }
+        })
+    }
+
+    // Never rejects a well-formed token; that is left to the DropInterp running alongside.
+    fn parse(&mut self, token: JsonToken<'_>) -> Option<()> {
+        match &mut self.value {
+            UnknownValue::String(ss, sr) => {
+                match call_str(ss, token, sr) {
+                    Ok(()) => {
+                        let value = sr.take()?;
+                        self.value = UnknownValue::Ended(value);
+                    }
+                    Err(None) => {}
+                    Err(Some(_)) => self.value = UnknownValue::TooLong,
+                }
+                return Some(());
+            }
+            UnknownValue::Number(ns, nr) => {
+                return match call_num(ns, token, nr) {
+                    Ok(()) => {
+                        let value = nr.take()?;
+                        self.value = UnknownValue::None;
+                        self.show(Some(&value))
+                    }
+                    Err(None) => Some(()),
+                    Err(Some(_)) => {
+                        self.value = UnknownValue::TooLong;
+                        Some(())
+                    }
+                };
+            }
+            // Skip the rest of a value that doesn't fit.
+            UnknownValue::TooLong if !is_structural(token) => return Some(()),
+            _ => {}
+        }
+        match core::mem::replace(&mut self.value, UnknownValue::None) {
+            UnknownValue::Ended(key) if token == JsonToken::NameSeparator => {
+                self.key = key.iter().copied().take(UNKNOWN_KEY_SIZE).collect();
+                return Some(());
+            }
+            UnknownValue::TooLong if token == JsonToken::NameSeparator => {
+                self.key = b"(too long to show)".iter().copied().collect();
+                return Some(());
+            }
+            UnknownValue::Ended(value) => self.show(Some(&value))?,
+            UnknownValue::TooLong => self.show(None)?,
+            _ => {}
+        }
+        if is_structural(token) {
+            return Some(());
+        }
+        let mut ss = init_str::<UNKNOWN_VALUE_SIZE>();
+        let mut sr = None;
+        match call_str(&mut ss, token, &mut sr) {
+            Ok(()) => {
+                self.value = UnknownValue::Ended(sr?);
+                return Some(());
+            }
+            Err(None) => {
+                self.value = UnknownValue::String(ss, sr);
+                return Some(());
+            }
+            Err(Some(_)) => {}
+        }
+        let mut ns = init_num::<UNKNOWN_VALUE_SIZE>();
+        let mut nr = None;
+        match call_num(&mut ns, token, &mut nr) {
+            Ok(()) => self.show(Some(&nr?)),
+            Err(None) => {
+                self.value = UnknownValue::Number(ns, nr);
+                Some(())
+            }
+            // true, false and null aren't worth a screen of their own.
+            Err(Some(_)) => Some(()),
+        }
+    }
+}
+
+// Shows each reward delegator as it is parsed, so the map needn't be held in memory, and rejects
+// the stake if the shares add up to more than 100 percent.
+#[derive(Debug)]
+pub struct RewardDelegatorsInterp;
+
+type TemporaryNumberState<const N: usize> =
+    <JsonStringAccumulate<N> as ParserCommon<JsonNumber>>::State;
+type TemporaryNumberReturn<const N: usize> =
+    Option<<JsonStringAccumulate<N> as ParserCommon<JsonNumber>>::Returning>;
+
+#[derive(Debug)]
+pub enum RewardDelegatorsStep {
+    Start,
+    KeyOrEnd,
+    Key(TemporaryStringState<64>, TemporaryStringReturn<64>),
+    KeySep,
+    Share(TemporaryNumberState<3>, TemporaryNumberReturn<3>),
+    ValueSepOrEnd,
+}
+
+#[derive(Debug)]
+pub struct RewardDelegatorsState {
+    total: u32,
+    count: usize,
+    address: ArrayVec<u8, 64>,
+    step: RewardDelegatorsStep,
+}
+
+fn init_num<const N: usize>() -> <JsonStringAccumulate<N> as ParserCommon<JsonNumber>>::State {
+    <JsonStringAccumulate<N> as ParserCommon<JsonNumber>>::init(&JsonStringAccumulate)
+}
+fn call_num<const N: usize>(
+    ss: &mut <JsonStringAccumulate<N> as ParserCommon<JsonNumber>>::State,
+    token: JsonToken<'_>,
+    dest: &mut Option<<JsonStringAccumulate<N> as ParserCommon<JsonNumber>>::Returning>,
+) -> Result<(), Option<OOB>> {
+    <JsonStringAccumulate<N> as JsonInterp<JsonNumber>>::parse(
+        &JsonStringAccumulate,
+        ss,
+        token,
+        dest,
+    )
+}
+
+fn parse_share(digits: &[u8]) -> Option<u32> {
+    if digits.is_empty() {
+        return None;
+    }
+    let mut share: u32 = 0;
+    for c in digits {
+        if !c.is_ascii_digit() {
+            return None;
+        }
+        share = share * 10 + (c - b'0') as u32;
+    }
+    Some(share)
+}
+
+impl ParserCommon<RewardDelegatorsSchema> for RewardDelegatorsInterp {
+    type State = RewardDelegatorsState;
+    type Returning = ();
+    fn init(&self) -> Self::State {
+        RewardDelegatorsState {
+            total: 0,
+            count: 0,
+            address: ArrayVec::new(),
+            step: RewardDelegatorsStep::Start,
+        }
+    }
+}
+
+impl JsonInterp<RewardDelegatorsSchema> for RewardDelegatorsInterp {
+    #[inline(never)]
+    fn parse(
+        &self,
+        state: &mut Self::State,
+        token: JsonToken<'_>,
+        destination: &mut Option<Self::Returning>,
+    ) -> Result<(), Option<OOB>> {
+        match state.step {
+            RewardDelegatorsStep::Start if token == JsonToken::BeginObject => {
+                set_from_thunk(&mut state.step, || RewardDelegatorsStep::KeyOrEnd);
+            }
+            RewardDelegatorsStep::KeyOrEnd if token == JsonToken::EndObject => {
+                *destination = Some(());
+                return Ok(());
+            }
+            RewardDelegatorsStep::KeyOrEnd => {
+                set_from_thunk(&mut state.step, || {
+                    RewardDelegatorsStep::Key(init_str::<64>(), None)
+                });
+                return self.parse(state, token, destination);
+            }
+            RewardDelegatorsStep::Key(ref mut temp_string_state, ref mut temp_string_return) => {
+                call_str::<64>(temp_string_state, token, temp_string_return)?;
+                state.address = temp_string_return.take().expect("should be set by now");
+                set_from_thunk(&mut state.step, || RewardDelegatorsStep::KeySep);
+            }
+            RewardDelegatorsStep::KeySep if token == JsonToken::NameSeparator => {
+                set_from_thunk(&mut state.step, || {
+                    RewardDelegatorsStep::Share(init_num::<3>(), None)
+                });
+            }
+            RewardDelegatorsStep::Share(ref mut temp_num_state, ref mut temp_num_return) => {
+                call_num::<3>(temp_num_state, token, temp_num_return)?;
+                let share = parse_share(temp_num_return.as_ref().expect("should be set by now"))
+                    .ok_or(Some(OOB::Reject))?;
+                state.total += share;
+                if state.total > 100 {
+                    return Err(Some(OOB::Reject));
+                }
+                state.count += 1;
+                let mut title: ArrayString<22> = ArrayString::new();
+                write!(mk_prompt_write(&mut title), "Delegator {}", state.count)
+                    .map_err(|_| Some(OOB::Reject))?;
+                let address = &state.address;
+                scroller_paginated(&title, |w| {
+                    Ok(write!(w, "{} ({}%)", from_utf8(address)?, share)?)
+                })
+                .ok_or(Some(OOB::Reject))?;
+                set_from_thunk(&mut state.step, || RewardDelegatorsStep::ValueSepOrEnd);
+            }
+            RewardDelegatorsStep::ValueSepOrEnd if token == JsonToken::ValueSeparator => {
+                set_from_thunk(&mut state.step, || {
+                    RewardDelegatorsStep::Key(init_str::<64>(), None)
+                });
+            }
+            RewardDelegatorsStep::ValueSepOrEnd if token == JsonToken::EndObject => {
+                *destination = Some(());
+                return Ok(());
+            }
+            _ => return Err(Some(OOB::Reject)),
+        };
+        Err(None)
+    }
+}
+
+impl ParserCommon<RewardDelegatorsSchema> for DropInterp {
+    type State = <DropInterp as ParserCommon<JsonAny>>::State;
+    type Returning = <DropInterp as ParserCommon<JsonAny>>::Returning;
+    fn init(&self) -> Self::State {
+        <DropInterp as ParserCommon<JsonAny>>::init(&DropInterp)
+    }
+}
+
+impl JsonInterp<RewardDelegatorsSchema> for DropInterp {
+    fn parse(
+        &self,
+        state: &mut Self::State,
+        token: JsonToken<'_>,
+        destination: &mut Option<Self::Returning>,
+    ) -> Result<(), Option<OOB>> {
+        <DropInterp as JsonInterp<JsonAny>>::parse(&DropInterp, state, token, destination)
+    }
+}
+
 pokt_cmd_definition! {}
 
 #[inline(never)]
@@ -1078,3 +2597,24 @@ pub fn get_blind_sign_state(
         }
     }
 }
+
+#[inline(never)]
+pub fn get_get_pubkeys_state(
+    s: &mut ParsersState,
+) -> &mut <GetPubkeysImplT as ParserCommon<Bip32Keys>>::State {
+    match s {
+        ParsersState::GetPubkeysState(_) => {}
+        _ => {
+            trace!("Non-same state found; initializing state.");
+            *s = ParsersState::GetPubkeysState(<GetPubkeysImplT as ParserCommon<Bip32Keys>>::init(
+                &GET_PUBKEYS_IMPL,
+            ));
+        }
+    }
+    match s {
+        ParsersState::GetPubkeysState(ref mut a) => a,
+        _ => {
+            unreachable!("Should be impossible because assignment right above")
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use core::convert::TryFrom;
-use ledger_device_sdk::io::{ApduHeader, StatusWords};
+use ledger_device_sdk::io::{ApduHeader, Reply, StatusWords};
 use ledger_parser_combinators::core_parsers::*;
 use ledger_parser_combinators::define_json_struct;
 use ledger_parser_combinators::endianness::*;
@@ -9,6 +9,10 @@ use num_enum::TryFromPrimitive;
 // Payload for a public key request
 pub type Bip32Key = DArray<Byte, U32<{ Endianness::Little }>, 10>;
 
+// Payload for a batch of public key requests
+pub const MAX_PUBKEY_BATCH: usize = 8;
+pub type Bip32Keys = DArray<Byte, Bip32Key, MAX_PUBKEY_BATCH>;
+
 define_json_struct! { Meta 16 {
     chainId: JsonString,
     sender: JsonString,
@@ -53,12 +57,24 @@ define_json_struct! { PublicKey 16 {
   value: JsonString
 }}
 
-define_json_struct! { StakeValue 16 {
+// Map from delegator address to its percentage of the node's rewards.
+pub struct RewardDelegatorsSchema;
+
+define_json_struct! { StakeValue 17 {
   chains: JsonArray<JsonString>,
   public_key: PublicKeySchema,
   service_url: JsonString,
   value: JsonString,
-  output_address: JsonString
+  output_address: JsonString,
+  reward_delegators: RewardDelegatorsSchema
+}}
+
+// Stake message from before pocket-core 8.0, which had no output address.
+define_json_struct! { LegacyStakeValue 16 {
+  chains: JsonArray<JsonString>,
+  public_key: PublicKeySchema,
+  service_url: JsonString,
+  value: JsonString
 }}
 
 define_json_struct! { UnstakeValue 17 {
@@ -66,6 +82,46 @@ define_json_struct! { UnstakeValue 17 {
   validator_address: JsonString
 }}
 
+define_json_struct! { AppStakeValue 16 {
+  chains: JsonArray<JsonString>,
+  pubkey: PublicKeySchema,
+  value: JsonString
+}}
+
+define_json_struct! { AppUnstakeValue 19 {
+  application_address: JsonString
+}}
+
+define_json_struct! { AppUnjailValue 16 {
+  address: JsonString
+}}
+
+define_json_struct! { DaoTransferValue 16 {
+  action: JsonString,
+  amount: JsonString,
+  from_address: JsonString,
+  to_address: JsonString
+}}
+
+define_json_struct! { ChangeParamValue 16 {
+  address: JsonString,
+  param_key: JsonString,
+  param_value: JsonString
+}}
+
+// Named UpgradeInfo rather than Upgrade to avoid colliding with the "upgrade" field below.
+define_json_struct! { UpgradeInfo 16 {
+  Features: JsonArray<JsonString>,
+  Height: JsonString,
+  OldUpgradeHeight: JsonString,
+  Version: JsonString
+}}
+
+define_json_struct! { UpgradeValue 16 {
+  address: JsonString,
+  upgrade: UpgradeInfoSchema
+}}
+
 pub struct MessageSchema;
 
 define_json_struct! { PoktCmd 16 {
@@ -98,6 +154,30 @@ pub type DoubledBlindSignParameters = (
     ),
 );
 
+// Status words for failures that the SDK's StatusWords have no code for, so that the host can
+// tell them apart.
+#[repr(u16)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AppSW {
+    UserRejected = 0x6985,
+    HostAborted = 0xB001,
+    HashMismatch = 0xB002,
+    MalformedBlock = 0xB003,
+    UnexpectedHostCommand = 0xB004,
+    UnsupportedMessageType = 0xB005,
+    ParseOverflow = 0xB006,
+    BadDerivationPath = 0xB007,
+    BlindSigningDisabled = 0xB008,
+    InvalidInput = 0xB009,
+    SenderMismatch = 0xB00A,
+}
+
+impl From<AppSW> for Reply {
+    fn from(sw: AppSW) -> Reply {
+        Reply(sw as u16)
+    }
+}
+
 #[repr(u8)]
 #[derive(Debug, TryFromPrimitive)]
 pub enum Ins {
@@ -106,10 +186,14 @@ pub enum Ins {
     GetPubkey = 2,
     Sign = 3,
     BlindSign = 4,
+    GetPubkeys = 5,
     GetVersionStr = 0xfe,
     Exit = 0xff,
 }
 
+// P1 for SIGN_TX and BLIND_SIGN_TX: also return the hash of the transaction after the signature.
+pub const P1_RETURN_TX_HASH: u8 = 0x01;
+
 impl TryFrom<ApduHeader> for Ins {
     type Error = StatusWords;
     fn try_from(m: ApduHeader) -> Result<Ins, Self::Error> {
@@ -117,9 +201,15 @@ impl TryFrom<ApduHeader> for Ins {
             ApduHeader {
                 cla: 0,
                 ins,
-                p1: 0,
+                p1,
                 p2: 0,
-            } => Self::try_from(ins).map_err(|_| StatusWords::BadIns),
+            } => {
+                let ins = Self::try_from(ins).map_err(|_| StatusWords::BadIns)?;
+                match (&ins, p1) {
+                    (_, 0) | (Ins::Sign | Ins::BlindSign, P1_RETURN_TX_HASH) => Ok(ins),
+                    _ => Err(StatusWords::BadIns),
+                }
+            }
             _ => Err(StatusWords::BadIns),
         }
     }
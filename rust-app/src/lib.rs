@@ -37,6 +37,12 @@ pub mod utils;
 #[cfg(target_family = "bolos")]
 pub mod implementation;
 
+#[cfg(target_family = "bolos")]
+pub mod warnings;
+
+#[cfg(target_family = "bolos")]
+pub mod rejection;
+
 #[cfg(target_family = "bolos")]
 pub mod menu;
 
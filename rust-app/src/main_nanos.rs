@@ -109,6 +109,17 @@ type BSHA256 = [u8; HASH_LEN];
 
 const MAX_PARAMS: usize = 2;
 
+// Results longer than one response are sent in pieces of this size with RESULT_ACCUMULATING.
+const MAX_RESULT_CHUNK_SIZE: usize = 200;
+
+// What the host has to send next, given the last thing we asked of it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Awaiting {
+    #[default]
+    Chunk,
+    ResultAccumulatingResponse,
+}
+
 // Replace with a proper implementation later; this is just to get enough to do the two-pass for
 // Ed25519.
 #[derive(Default)]
@@ -116,15 +127,28 @@ struct BlockState {
     params: ArrayVec<BSHA256, MAX_PARAMS>,
     requested_block: BSHA256,
     state: usize,
+    awaiting: Awaiting,
+    // Bytes of the current parameter already parsed, for reporting where a rejection happened.
+    param_offset: usize,
+    // How much of the result, kept in the ParsersState, has been sent.
+    result_sent: usize,
+    // Whether a signature is followed by the transaction hash; set by the P1 of the START APDU.
+    return_tx_hash: bool,
+}
+
+fn hash_block(data: &[u8]) -> BSHA256 {
+    let mut hasher = SHA256::new();
+    hasher.update(data);
+    hasher.finalize::<Base64Hash<{ SHA256::N }>>().0
 }
 
 #[repr(u8)]
 #[derive(Copy, Clone)]
 enum LedgerToHostCmd {
-    // ResultAccumulating = 0, // Not used yet in this app.
+    ResultAccumulating = 0,
     ResultFinal = 1,
     GetChunk = 2,
-    // PutChunk = 3
+    // PutChunk = 3, // Not used by this app.
 }
 
 #[repr(u8)]
@@ -146,7 +170,7 @@ impl TryFrom<u8> for HostToLedgerCmd {
             2 => Ok(HostToLedgerCmd::GetChunkResponseFailure),
             3 => Ok(HostToLedgerCmd::PutChunkResponse),
             4 => Ok(HostToLedgerCmd::ResultAccumulatingResponse),
-            _ => Err(io::StatusWords::Unknown.into()),
+            _ => Err(AppSW::UnexpectedHostCommand.into()),
         }
     }
 }
@@ -207,62 +231,135 @@ impl BlockyAdapterScheme for OneParamOnceState {
 
 use ledger_parser_combinators::interp_parser::ParserCommon;
 
+// Leaves nothing of a command behind, so that whatever the host sends next starts afresh.
+fn reset_block_protocol(states: &mut ParsersState, block_state: &mut BlockState) {
+    *block_state = BlockState::default();
+    reset_parsers_state(states);
+}
+
+fn request_block(block_state: &mut BlockState, comm: &mut io::Comm) {
+    comm.append(&[LedgerToHostCmd::GetChunk as u8]);
+    comm.append(&block_state.requested_block);
+    block_state.awaiting = Awaiting::Chunk;
+}
+
+// Sends as much of the remaining result as fits in one response; the host asks for the rest with
+// RESULT_ACCUMULATING_RESPONSE. The parser state is reset once the last of it is sent.
+fn send_result(
+    states: &mut ParsersState,
+    block_state: &mut BlockState,
+    comm: &mut io::Comm,
+) -> Result<(), Reply> {
+    let result = match states {
+        ParsersState::ResultState(ref result) => result,
+        // Cancelled from the busy menu while the host was still collecting the result.
+        _ => {
+            reset_block_protocol(states, block_state);
+            return Err(AppSW::UnexpectedHostCommand.into());
+        }
+    };
+    let remaining = &result[block_state.result_sent..];
+    if remaining.len() <= MAX_RESULT_CHUNK_SIZE {
+        comm.append(&[LedgerToHostCmd::ResultFinal as u8]);
+        comm.append(remaining);
+        block_state.result_sent = 0;
+        block_state.awaiting = Awaiting::Chunk;
+        reset_parsers_state(states);
+    } else {
+        comm.append(&[LedgerToHostCmd::ResultAccumulating as u8]);
+        comm.append(&remaining[..MAX_RESULT_CHUNK_SIZE]);
+        block_state.result_sent += MAX_RESULT_CHUNK_SIZE;
+        block_state.awaiting = Awaiting::ResultAccumulatingResponse;
+    }
+    Ok(())
+}
+
+// The host asking for the rest of a result that is already being sent; nothing more is parsed or
+// signed, so commands that are only allowed under some settings let it through.
+fn is_result_continuation(block_state: &BlockState, comm: &io::Comm) -> bool {
+    block_state.awaiting == Awaiting::ResultAccumulatingResponse
+        && matches!(
+            comm.get_data(),
+            Ok([cmd, ..]) if *cmd == HostToLedgerCmd::ResultAccumulatingResponse as u8
+        )
+}
+
 #[inline(never)]
-fn run_parser_apdu<P: InterpParser<A, Returning = ArrayVec<u8, 128>>, A, const N: usize>(
+fn run_parser_apdu<
+    P: InterpParser<A, Returning = ArrayVec<u8, R>>,
+    A,
+    const N: usize,
+    const R: usize,
+>(
     states: &mut ParsersState,
     get_state: fn(&mut ParsersState) -> &mut <P as ParserCommon<A>>::State,
     block_state: &mut BlockState,
     seq: &[usize; N],
     parser: &P,
+    // How many bytes at the end of the result are the transaction hash, for signing commands.
+    tx_hash_len: usize,
     comm: &mut io::Comm,
 ) -> Result<(), Reply> {
     trace!("Entered run_parser_apdu_signing");
     let block: &[u8] = comm.get_data()?;
 
     let host_cmd: HostToLedgerCmd =
-        HostToLedgerCmd::try_from(*block.first().ok_or(io::StatusWords::Unknown)?)?;
+        match HostToLedgerCmd::try_from(*block.first().ok_or(AppSW::MalformedBlock)?) {
+            Ok(cmd) => cmd,
+            Err(e) => {
+                reset_block_protocol(states, block_state);
+                return Err(e);
+            }
+        };
 
     trace!("Host cmd: {:?}", host_cmd);
     match host_cmd {
         HostToLedgerCmd::Start => {
-            *block_state = BlockState::default();
-            reset_parsers_state(states);
+            reset_block_protocol(states, block_state);
+            // Hosts written before the hash was returned take the whole response as the
+            // signature, so it is only sent to those that ask for it.
+            block_state.return_tx_hash = comm.get_apdu_metadata().p1 == P1_RETURN_TX_HASH;
             block_state.params.clear();
             for param in block[1..].chunks_exact(HASH_LEN) {
                 block_state
                     .params
-                    .try_push(param.try_into().or(Err(io::StatusWords::Unknown))?)
-                    .or(Err(io::StatusWords::Unknown))?;
+                    .try_push(param.try_into().or(Err(AppSW::MalformedBlock))?)
+                    .or(Err(AppSW::MalformedBlock))?;
             }
             trace!("Params: {:x?}", block_state.params);
             block_state.state = 0;
             if block_state.params.len() <= *seq.iter().max().unwrap() {
-                return Err(io::StatusWords::Unknown.into());
+                return Err(AppSW::MalformedBlock.into());
             }
             block_state
                 .requested_block
                 .copy_from_slice(&block_state.params[seq[block_state.state]][..]);
-            comm.append(&[LedgerToHostCmd::GetChunk as u8]);
-            comm.append(&block_state.requested_block);
+            request_block(block_state, comm);
             Ok(())
         }
         HostToLedgerCmd::GetChunkResponseSuccess => {
+            if block_state.awaiting != Awaiting::Chunk {
+                reset_block_protocol(states, block_state);
+                return Err(AppSW::UnexpectedHostCommand.into());
+            }
             if block.len() < HASH_LEN + 1 {
-                return Err(io::StatusWords::Unknown.into());
+                reset_block_protocol(states, block_state);
+                return Err(AppSW::MalformedBlock.into());
             }
 
             // Check the hash, so the host can't lie.
-            call_me_maybe(|| {
-                let mut hasher = SHA256::new();
-                hasher.update(&block[1..]);
-                let hashed = hasher.finalize::<Base64Hash<{ SHA256::N }>>();
-                if hashed.0 != block_state.requested_block {
+            if call_me_maybe(|| {
+                if hash_block(&block[1..]) != block_state.requested_block {
                     None
                 } else {
                     Some(())
                 }
             })
-            .ok_or(io::StatusWords::Unknown)?;
+            .is_none()
+            {
+                reset_block_protocol(states, block_state);
+                return Err(AppSW::HashMismatch.into());
+            }
 
             let next_block = &block[1..1 + HASH_LEN];
             let cursor = &block[1 + HASH_LEN..];
@@ -276,10 +373,15 @@ fn run_parser_apdu<P: InterpParser<A, Returning = ArrayVec<u8, 128>>, A, const N
             trace!("Parser result: {:?}\n", parse_rv);
             trace!("Parse destination: {:?}\n", parse_destination);
             match parse_rv {
-                // Explicit rejection; reset the parser. Possibly send error message to host?
-                Err((Some(OOB::Reject), _)) => {
+                // Explicit rejection; reset the parser and tell the host why, and where.
+                Err((Some(OOB::Reject), rest)) => {
+                    let rejection = take_rejection();
+                    let offset = block_state.param_offset + (cursor.len() - rest.len());
+                    comm.append(&[rejection.reason as u8, seq[block_state.state] as u8]);
+                    comm.append(&(offset as u32).to_le_bytes());
+                    comm.append(&rejection.field);
                     reset_parsers_state(states);
-                    Err(io::StatusWords::Unknown.into())
+                    Err(rejection.status.into())
                 }
                 // Deliberately no catch-all on the Err((Some case; we'll get error messages if we
                 // add to OOB's out-of-band actions and forget to implement them.
@@ -289,52 +391,82 @@ fn run_parser_apdu<P: InterpParser<A, Returning = ArrayVec<u8, 128>>, A, const N
                     trace!("Parser needs more; get more.");
                     // Request the next chunk of our input.
                     let our_next_block: &[u8] = if next_block == [0; 32] {
+                        block_state.param_offset = 0;
                         block_state.state += 1;
                         if block_state.state > seq.len() {
-                            return Err(io::StatusWords::Unknown.into());
+                            return Err(AppSW::MalformedBlock.into());
                         }
                         if block_state.params.len() <= seq[block_state.state] {
-                            return Err(io::StatusWords::Unknown.into());
+                            return Err(AppSW::MalformedBlock.into());
                         }
                         &block_state.params[seq[block_state.state]]
                     } else {
+                        block_state.param_offset += cursor.len();
                         next_block
                     };
                     trace!("Next block: {:x?}", our_next_block);
 
                     block_state.requested_block.copy_from_slice(our_next_block);
-                    comm.append(&[LedgerToHostCmd::GetChunk as u8]);
-                    comm.append(&block_state.requested_block);
                     trace!("Requesting next block from host");
+                    request_block(block_state, comm);
                     Ok(())
                 }
                 // Didn't consume the whole chunk; reset and error message.
                 Err((None, _)) => {
                     reset_parsers_state(states);
-                    Err(io::StatusWords::Unknown.into())
+                    Err(AppSW::MalformedBlock.into())
                 }
                 // Consumed the whole chunk and parser finished; send response.
                 Ok([]) => {
                     trace!("Parser finished, resetting state\n");
                     match parse_destination.as_ref() {
+                        // Parse finished; the result replaces the parser's state until sent.
                         Some(rv) => {
-                            comm.append(&[LedgerToHostCmd::ResultFinal as u8]);
-                            comm.append(&rv[..]);
+                            let rv = if block_state.return_tx_hash {
+                                &rv[..]
+                            } else {
+                                &rv[..rv.len().saturating_sub(tx_hash_len)]
+                            };
+                            if set_result_state(states, rv).is_none() {
+                                reset_parsers_state(states);
+                                return Err(AppSW::ParseOverflow.into());
+                            }
+                            block_state.result_sent = 0;
+                            send_result(states, block_state, comm)
+                        }
+                        None => {
+                            reset_parsers_state(states);
+                            Err(AppSW::InvalidInput.into())
                         }
-                        None => return Err(io::StatusWords::Unknown.into()),
                     }
-                    // Parse finished; reset.
-                    reset_parsers_state(states);
-                    Ok(())
                 }
                 // Parse ended before the chunk did; reset.
                 Ok(_) => {
                     reset_parsers_state(states);
-                    Err(io::StatusWords::Unknown.into())
+                    Err(AppSW::MalformedBlock.into())
                 }
             }
         }
-        _ => Err(io::StatusWords::Unknown.into()),
+        // This app never sends PUT_CHUNK, so a response to one is out of order.
+        HostToLedgerCmd::PutChunkResponse => {
+            reset_block_protocol(states, block_state);
+            Err(AppSW::UnexpectedHostCommand.into())
+        }
+        HostToLedgerCmd::ResultAccumulatingResponse => {
+            if block_state.awaiting != Awaiting::ResultAccumulatingResponse {
+                reset_block_protocol(states, block_state);
+                return Err(AppSW::UnexpectedHostCommand.into());
+            }
+            send_result(states, block_state, comm)
+        }
+        // The host couldn't supply a chunk we asked for, so the command can't go on.
+        HostToLedgerCmd::GetChunkResponseFailure => {
+            reset_block_protocol(states, block_state);
+            write_scroller(false, "Host aborted", |w| {
+                Ok(write!(w, "Request cancelled")?)
+            });
+            Err(AppSW::HostAborted.into())
+        }
     }
 }
 
@@ -362,43 +494,57 @@ fn handle_apdu(
             ]);
             comm.append(b"Pocket");
         }
-        Ins::VerifyAddress => run_parser_apdu::<_, Bip32Key, _>(
+        Ins::VerifyAddress => run_parser_apdu::<_, Bip32Key, _, _>(
             parser,
             get_get_address_state::<true>,
             block_state,
             &[0],
             &get_address_impl::<true>(),
+            0,
             comm,
         )?,
-        Ins::GetPubkey => run_parser_apdu::<_, Bip32Key, _>(
+        Ins::GetPubkey => run_parser_apdu::<_, Bip32Key, _, _>(
             parser,
             get_get_address_state::<false>,
             block_state,
             &[0],
             &get_address_impl::<false>(),
+            0,
             comm,
         )?,
-        Ins::Sign => run_parser_apdu::<_, DoubledSignParameters, _>(
+        Ins::Sign => run_parser_apdu::<_, DoubledSignParameters, _, _>(
             parser,
             get_sign_state,
             block_state,
             &SIGN_SEQ,
             &SIGN_IMPL,
+            TX_HASH_LEN,
+            comm,
+        )?,
+        Ins::GetPubkeys => run_parser_apdu::<_, Bip32Keys, _, _>(
+            parser,
+            get_get_pubkeys_state,
+            block_state,
+            &[0],
+            &GET_PUBKEYS_IMPL,
+            0,
             comm,
         )?,
         Ins::BlindSign => {
-            if settings.get() != 1 {
+            // Once signed, blind signing may have expired before the host has all of the result.
+            if !settings.blind_signing() && !is_result_continuation(block_state, comm) {
                 write_scroller(false, "Blind Signing must", |w| {
                     Ok(write!(w, "be enabled")?)
                 });
-                return Err(io::SyscallError::NotSupported.into());
+                return Err(AppSW::BlindSigningDisabled.into());
             } else {
-                run_parser_apdu::<_, DoubledBlindSignParameters, _>(
+                run_parser_apdu::<_, DoubledBlindSignParameters, _, _>(
                     parser,
                     get_blind_sign_state,
                     block_state,
                     &SIGN_SEQ,
                     &BLIND_SIGN_IMPL,
+                    TX_HASH_LEN,
                     comm,
                 )?
             }
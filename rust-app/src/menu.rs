@@ -23,8 +23,10 @@ pub enum IdleMenu {
 }
 
 pub enum SettingsSubMenu {
-    EnableBlindSigning,
-    DisableBlindSigning,
+    BlindSigning,
+    BlindSigningExpires,
+    SenderCheck,
+    ExpertMode,
     Back,
 }
 
@@ -44,14 +46,11 @@ impl Menu for IdleMenuWithSettings {
             AppMain => self.idle_menu = Exit,
             ShowVersion => self.idle_menu = AppMain,
             Settings(None) => self.idle_menu = ShowVersion,
-            Settings(Some(Back)) => {
-                if self.settings.get() == 1 {
-                    self.idle_menu = Settings(Some(DisableBlindSigning))
-                } else {
-                    self.idle_menu = Settings(Some(EnableBlindSigning))
-                }
-            }
-            Settings(Some(_)) => self.idle_menu = Settings(Some(Back)),
+            Settings(Some(BlindSigning)) => self.idle_menu = Settings(Some(Back)),
+            Settings(Some(BlindSigningExpires)) => self.idle_menu = Settings(Some(BlindSigning)),
+            Settings(Some(SenderCheck)) => self.idle_menu = Settings(Some(BlindSigningExpires)),
+            Settings(Some(ExpertMode)) => self.idle_menu = Settings(Some(SenderCheck)),
+            Settings(Some(Back)) => self.idle_menu = Settings(Some(ExpertMode)),
             Exit => self.idle_menu = Settings(None),
         };
     }
@@ -62,14 +61,11 @@ impl Menu for IdleMenuWithSettings {
             AppMain => self.idle_menu = ShowVersion,
             ShowVersion => self.idle_menu = Settings(None),
             Settings(None) => self.idle_menu = Exit,
-            Settings(Some(Back)) => {
-                if self.settings.get() == 1 {
-                    self.idle_menu = Settings(Some(DisableBlindSigning))
-                } else {
-                    self.idle_menu = Settings(Some(EnableBlindSigning))
-                }
-            }
-            Settings(Some(_)) => self.idle_menu = Settings(Some(Back)),
+            Settings(Some(BlindSigning)) => self.idle_menu = Settings(Some(BlindSigningExpires)),
+            Settings(Some(BlindSigningExpires)) => self.idle_menu = Settings(Some(SenderCheck)),
+            Settings(Some(SenderCheck)) => self.idle_menu = Settings(Some(ExpertMode)),
+            Settings(Some(ExpertMode)) => self.idle_menu = Settings(Some(Back)),
+            Settings(Some(Back)) => self.idle_menu = Settings(Some(BlindSigning)),
             Exit => self.idle_menu = AppMain,
         };
     }
@@ -81,21 +77,31 @@ impl Menu for IdleMenuWithSettings {
             AppMain => None,
             ShowVersion => None,
             Settings(None) => {
-                if self.settings.get() == 1 {
-                    self.idle_menu = Settings(Some(DisableBlindSigning))
-                } else {
-                    self.idle_menu = Settings(Some(EnableBlindSigning))
+                self.idle_menu = Settings(Some(BlindSigning));
+                None
+            }
+            Settings(Some(BlindSigning)) => {
+                let enabled = self.settings.blind_signing();
+                self.settings.set_blind_signing(!enabled);
+                None
+            }
+            Settings(Some(BlindSigningExpires)) => {
+                let next = match self.settings.blind_signing_expiry() {
+                    BlindSigningExpiry::Never => BlindSigningExpiry::OneSignature,
+                    BlindSigningExpiry::OneSignature => BlindSigningExpiry::AppRestart,
+                    BlindSigningExpiry::AppRestart => BlindSigningExpiry::Never,
                 };
+                self.settings.set_blind_signing_expiry(next);
                 None
             }
-            Settings(Some(EnableBlindSigning)) => {
-                self.settings.set(&1);
-                self.idle_menu = Settings(Some(DisableBlindSigning));
+            Settings(Some(SenderCheck)) => {
+                let enabled = self.settings.allow_sender_mismatch();
+                self.settings.set_allow_sender_mismatch(!enabled);
                 None
             }
-            Settings(Some(DisableBlindSigning)) => {
-                self.settings.set(&0);
-                self.idle_menu = Settings(Some(EnableBlindSigning));
+            Settings(Some(ExpertMode)) => {
+                let enabled = self.settings.expert_mode();
+                self.settings.set_expert_mode(!enabled);
                 None
             }
             Settings(Some(Back)) => {
@@ -131,17 +137,51 @@ impl Menu for IdleMenuWithSettings {
                     bold: true,
                 },
             ),
-            Settings(Some(EnableBlindSigning)) => (
+            Settings(Some(BlindSigning)) => (
                 MenuLabelTop::Text("Blind Signing"),
                 MenuLabelBottom {
-                    text: "Disabled",
+                    text: match (
+                        self.settings.blind_signing(),
+                        self.settings.blind_signing_expiry(),
+                    ) {
+                        (false, _) => "Disabled",
+                        (true, BlindSigningExpiry::Never) => "Enabled",
+                        (true, BlindSigningExpiry::OneSignature) => "For one signature",
+                        (true, BlindSigningExpiry::AppRestart) => "Until restart",
+                    },
                     bold: false,
                 },
             ),
-            Settings(Some(DisableBlindSigning)) => (
-                MenuLabelTop::Text("Blind Signing"),
+            Settings(Some(BlindSigningExpires)) => (
+                MenuLabelTop::Text("Blind Sign Expiry"),
+                MenuLabelBottom {
+                    text: match self.settings.blind_signing_expiry() {
+                        BlindSigningExpiry::Never => "Never",
+                        BlindSigningExpiry::OneSignature => "After one signature",
+                        BlindSigningExpiry::AppRestart => "On app restart",
+                    },
+                    bold: false,
+                },
+            ),
+            Settings(Some(SenderCheck)) => (
+                MenuLabelTop::Text("Sender Check"),
+                MenuLabelBottom {
+                    text: if self.settings.allow_sender_mismatch() {
+                        "Warn only"
+                    } else {
+                        "Reject"
+                    },
+                    bold: false,
+                },
+            ),
+            Settings(Some(ExpertMode)) => (
+                MenuLabelTop::Text("Expert Mode"),
                 MenuLabelBottom {
-                    text: "Enabled",
+                    text: if self.settings.expert_mode() {
+                        "Enabled"
+                    } else {
+                        "Disabled"
+                    },
                     bold: false,
                 },
             ),
@@ -0,0 +1,294 @@
+use crate::interface::*;
+use arrayvec::ArrayVec;
+use ledger_parser_combinators::interp_parser::{ParserCommon, OOB};
+use ledger_parser_combinators::json::*;
+use ledger_parser_combinators::json_interp::*;
+
+// Enough for the deepest field of a transaction, like "msg.value.upgrade.Features[0]"; longer
+// paths, such as those through a reward delegator's address, are cut short with an ellipsis.
+pub const MAX_FIELD_PATH: usize = 32;
+const ELLIPSIS: &[u8] = b"...";
+// The transaction, the message, its value, an object in that, and an array in that.
+const MAX_FIELD_DEPTH: usize = 5;
+
+// Sent to the host along with the status word, so it can say what was wrong with its input.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RejectReason {
+    // Refused by the app's own checks, or by the user.
+    Refused = 0,
+    // Not the JSON structure the field needs, such as an array where an object should be.
+    UnexpectedToken = 1,
+    // A value of the wrong type, or not allowed.
+    InvalidValue = 2,
+    // A message type this app can't sign.
+    UnknownType = 3,
+    // A string too long for the app to keep.
+    TooLong = 4,
+    // A key that the object doesn't have.
+    UnknownField = 5,
+}
+
+pub struct Rejection {
+    pub status: AppSW,
+    pub reason: RejectReason,
+    pub field: ArrayVec<u8, MAX_FIELD_PATH>,
+}
+
+// The combinators only say that a parse was rejected, so why, and where, is recorded here.
+static mut REJECT_STATUS: Option<AppSW> = None;
+static mut REJECT_REASON: Option<RejectReason> = None;
+static mut REJECT_FIELD: Option<ArrayVec<u8, MAX_FIELD_PATH>> = None;
+
+pub fn reject<T>(status: AppSW) -> Option<T> {
+    unsafe {
+        REJECT_STATUS = Some(status);
+        REJECT_REASON = Some(match status {
+            AppSW::UnsupportedMessageType => RejectReason::UnknownType,
+            _ => RejectReason::Refused,
+        });
+    }
+    None
+}
+
+// Rejections made inside the combinators, such as a token of the wrong type, record no status of
+// their own.
+pub fn take_rejection() -> Rejection {
+    unsafe {
+        Rejection {
+            status: REJECT_STATUS.take().unwrap_or(AppSW::InvalidInput),
+            reason: REJECT_REASON.take().unwrap_or(RejectReason::Refused),
+            field: REJECT_FIELD.take().unwrap_or_default(),
+        }
+    }
+}
+
+pub fn clear_rejection() {
+    unsafe {
+        REJECT_STATUS = None;
+        REJECT_REASON = None;
+        REJECT_FIELD = None;
+    }
+}
+
+// Punctuation, as opposed to the tokens that carry a value.
+pub fn is_structural(token: JsonToken<'_>) -> bool {
+    token == JsonToken::BeginObject
+        || token == JsonToken::EndObject
+        || token == JsonToken::BeginArray
+        || token == JsonToken::EndArray
+        || token == JsonToken::NameSeparator
+        || token == JsonToken::ValueSeparator
+}
+
+// Passes every token on to the inner interpreter while keeping track of the field it belongs to,
+// so that if the inner interpreter rejects the token the field can be reported.
+#[derive(Debug)]
+pub struct TrackPath<I>(pub I);
+
+#[derive(Debug)]
+pub struct TrackPathState<S> {
+    inner: S,
+    path: FieldPath,
+}
+
+impl<T, I: JsonInterp<T>> ParserCommon<T> for TrackPath<I> {
+    type State = TrackPathState<<I as ParserCommon<T>>::State>;
+    type Returning = <I as ParserCommon<T>>::Returning;
+    fn init(&self) -> Self::State {
+        TrackPathState {
+            inner: self.0.init(),
+            path: FieldPath::default(),
+        }
+    }
+}
+
+impl<T, I: JsonInterp<T>> JsonInterp<T> for TrackPath<I> {
+    #[inline(never)]
+    fn parse(
+        &self,
+        state: &mut Self::State,
+        token: JsonToken<'_>,
+        destination: &mut Option<Self::Returning>,
+    ) -> Result<(), Option<OOB>> {
+        state.path.update(token);
+        let rv = self.0.parse(&mut state.inner, token, destination);
+        if let Err(Some(OOB::Reject)) = rv {
+            state.path.record_rejection(token);
+        }
+        rv
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum FrameKind {
+    ObjectKey,
+    ObjectValue,
+    Array(u16),
+}
+
+// Where in the path a nested object or array starts, so that its keys and indices can be
+// replaced as they change.
+#[derive(Clone, Copy, Debug)]
+struct Frame {
+    start: u16,
+    kind: FrameKind,
+}
+
+#[derive(Debug, Default)]
+struct FieldPath {
+    path: ArrayVec<u8, MAX_FIELD_PATH>,
+    // The length the path would have with room for all of it.
+    len: u16,
+    frames: ArrayVec<Frame, MAX_FIELD_DEPTH>,
+    // Objects and arrays nested too deeply to have frames; the path stops at the last frame.
+    untracked_depth: usize,
+    // Whether the last token was part of a key, or the separator after one.
+    in_key: bool,
+}
+
+impl FieldPath {
+    fn update(&mut self, token: JsonToken<'_>) {
+        self.in_key = false;
+        let begins = token == JsonToken::BeginObject || token == JsonToken::BeginArray;
+        let ends = token == JsonToken::EndObject || token == JsonToken::EndArray;
+        if self.untracked_depth > 0 {
+            if begins {
+                self.untracked_depth += 1;
+            } else if ends {
+                self.untracked_depth -= 1;
+            }
+            return;
+        }
+        if begins {
+            return self.begin(token == JsonToken::BeginArray);
+        }
+        let frame = match self.frames.last_mut() {
+            Some(frame) => frame,
+            None => return,
+        };
+        match frame.kind {
+            FrameKind::ObjectKey if token == JsonToken::EndObject => self.end(),
+            // The key goes straight into the path, so that it is there even if it is refused.
+            FrameKind::ObjectKey => {
+                self.in_key = true;
+                match token {
+                    JsonToken::BeginString => {
+                        let start = frame.start;
+                        self.truncate(start);
+                        if start > 0 {
+                            self.push_path(b".");
+                        }
+                    }
+                    JsonToken::StringChunk(bytes) => self.push_path(bytes),
+                    JsonToken::EndString => frame.kind = FrameKind::ObjectValue,
+                    _ => {}
+                }
+            }
+            FrameKind::ObjectValue if token == JsonToken::NameSeparator => self.in_key = true,
+            FrameKind::ObjectValue if token == JsonToken::ValueSeparator => {
+                frame.kind = FrameKind::ObjectKey;
+            }
+            FrameKind::ObjectValue if token == JsonToken::EndObject => self.end(),
+            FrameKind::Array(index) if token == JsonToken::ValueSeparator => {
+                let start = frame.start;
+                let index = index.saturating_add(1);
+                frame.kind = FrameKind::Array(index);
+                self.truncate(start);
+                self.push_index(index);
+            }
+            FrameKind::Array(_) if token == JsonToken::EndArray => self.end(),
+            _ => {}
+        }
+    }
+
+    fn begin(&mut self, array: bool) {
+        let start = self.len;
+        let kind = if array {
+            FrameKind::Array(0)
+        } else {
+            FrameKind::ObjectKey
+        };
+        if self.frames.try_push(Frame { start, kind }).is_err() {
+            self.untracked_depth = 1;
+            return;
+        }
+        if array {
+            self.push_index(0);
+        }
+    }
+
+    fn end(&mut self) {
+        if let Some(frame) = self.frames.pop() {
+            self.truncate(frame.start);
+        }
+    }
+
+    fn truncate(&mut self, len: u16) {
+        self.len = len;
+        self.path.truncate(len as usize);
+    }
+
+    fn push_path(&mut self, bytes: &[u8]) {
+        self.len = self.len.saturating_add(bytes.len() as u16);
+        let room = self.path.remaining_capacity().min(bytes.len());
+        let _ = self.path.try_extend_from_slice(&bytes[..room]);
+    }
+
+    fn push_index(&mut self, index: u16) {
+        let mut digits = ArrayVec::<u8, 5>::new();
+        let mut n = index;
+        loop {
+            digits.push(b'0' + (n % 10) as u8);
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        digits.reverse();
+        self.push_path(b"[");
+        self.push_path(&digits);
+        self.push_path(b"]");
+    }
+
+    // The path as far as there was room for it, ending in an ellipsis if it went further.
+    fn reported_path(&self) -> ArrayVec<u8, MAX_FIELD_PATH> {
+        let mut path = self.path.clone();
+        if (self.len as usize) > path.len() || self.untracked_depth > 0 {
+            path.truncate(MAX_FIELD_PATH - ELLIPSIS.len());
+            let _ = path.try_extend_from_slice(ELLIPSIS);
+        }
+        path
+    }
+
+    // An action that refuses a value records its own status and reason with reject, before this
+    // sees the rejection; the field is only known here, so it is always filled in.
+    fn record_rejection(&self, token: JsonToken<'_>) {
+        unsafe {
+            if REJECT_FIELD.is_none() {
+                REJECT_FIELD = Some(self.reported_path());
+            }
+            if REJECT_REASON.is_none() {
+                let reason = if self.in_key {
+                    RejectReason::UnknownField
+                } else if token == JsonToken::EndObject || token == JsonToken::EndArray {
+                    // A closing token is where an action checks the value it has just finished,
+                    // such as an object missing a field it needs.
+                    RejectReason::InvalidValue
+                } else if is_structural(token) {
+                    RejectReason::UnexpectedToken
+                } else if matches!(token, JsonToken::StringChunk(_)) {
+                    // A string is only refused part way through when it has filled the buffer it
+                    // is accumulated into.
+                    RejectReason::TooLong
+                } else {
+                    RejectReason::InvalidValue
+                };
+                if reason == RejectReason::TooLong {
+                    REJECT_STATUS = Some(AppSW::ParseOverflow);
+                }
+                REJECT_REASON = Some(reason);
+            }
+        }
+    }
+}
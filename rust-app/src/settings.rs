@@ -5,6 +5,25 @@ use ledger_device_sdk::NVMData;
 #[link_section = ".nvm_data"]
 static mut SETTINGS: NVMData<AtomicStorage<u8>> = NVMData::new(AtomicStorage::new(&0));
 
+// Each setting is one bit; blind signing keeps bit 0 so that the value stored by earlier versions
+// still reads the same.
+const BLIND_SIGNING: u8 = 1 << 0;
+const ALLOW_SENDER_MISMATCH: u8 = 1 << 1;
+const EXPERT_MODE: u8 = 1 << 2;
+const BLIND_SIGNING_EXPIRY_SHIFT: u8 = 3;
+const BLIND_SIGNING_EXPIRY: u8 = 0b11 << BLIND_SIGNING_EXPIRY_SHIFT;
+
+// Blind signing that is meant to expire is only ever enabled here, in RAM, so that it can't outlive
+// the app even when the expiry is after one signature.
+static mut BLIND_SIGNING_SESSION: bool = false;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum BlindSigningExpiry {
+    Never = 0,
+    OneSignature = 1,
+    AppRestart = 2,
+}
+
 #[derive(Clone, Copy)]
 pub struct Settings;
 
@@ -27,4 +46,75 @@ impl Settings {
         let settings = unsafe { SETTINGS.get_mut() };
         settings.update(v);
     }
+
+    fn get_flag(&self, flag: u8) -> bool {
+        self.get() & flag != 0
+    }
+
+    fn set_flag(&mut self, flag: u8, enabled: bool) {
+        let v = if enabled {
+            self.get() | flag
+        } else {
+            self.get() & !flag
+        };
+        self.set(&v);
+    }
+
+    pub fn blind_signing(&self) -> bool {
+        match self.blind_signing_expiry() {
+            BlindSigningExpiry::Never => self.get_flag(BLIND_SIGNING),
+            _ => unsafe { BLIND_SIGNING_SESSION },
+        }
+    }
+
+    pub fn set_blind_signing(&mut self, enabled: bool) {
+        match self.blind_signing_expiry() {
+            BlindSigningExpiry::Never => self.set_flag(BLIND_SIGNING, enabled),
+            _ => unsafe { BLIND_SIGNING_SESSION = enabled },
+        }
+    }
+
+    pub fn blind_signing_expiry(&self) -> BlindSigningExpiry {
+        match (self.get() & BLIND_SIGNING_EXPIRY) >> BLIND_SIGNING_EXPIRY_SHIFT {
+            1 => BlindSigningExpiry::OneSignature,
+            2 => BlindSigningExpiry::AppRestart,
+            _ => BlindSigningExpiry::Never,
+        }
+    }
+
+    // Changing the expiry also turns blind signing off, rather than moving it between NVM and RAM.
+    pub fn set_blind_signing_expiry(&mut self, expiry: BlindSigningExpiry) {
+        let v = (self.get() & !(BLIND_SIGNING | BLIND_SIGNING_EXPIRY))
+            | ((expiry as u8) << BLIND_SIGNING_EXPIRY_SHIFT);
+        self.set(&v);
+        unsafe {
+            BLIND_SIGNING_SESSION = false;
+        }
+    }
+
+    // Called once a blind signature has been produced.
+    pub fn blind_signature_made(&mut self) {
+        if self.blind_signing_expiry() == BlindSigningExpiry::OneSignature {
+            self.set_blind_signing(false);
+        }
+    }
+
+    // Whether a transfer whose from_address isn't the signing key is shown with a warning rather
+    // than rejected outright.
+    pub fn allow_sender_mismatch(&self) -> bool {
+        self.get_flag(ALLOW_SENDER_MISMATCH)
+    }
+
+    pub fn set_allow_sender_mismatch(&mut self, enabled: bool) {
+        self.set_flag(ALLOW_SENDER_MISMATCH, enabled)
+    }
+
+    // Whether signing also shows the fields that the normal flow leaves out.
+    pub fn expert_mode(&self) -> bool {
+        self.get_flag(EXPERT_MODE)
+    }
+
+    pub fn set_expert_mode(&mut self, enabled: bool) {
+        self.set_flag(EXPERT_MODE, enabled)
+    }
 }
@@ -0,0 +1,99 @@
+use crate::utils::scroller_paginated;
+use arrayvec::ArrayVec;
+use core::cmp::Ordering;
+use core::fmt::Write;
+
+// The parts of a message that the warnings look at; returned by the message interpreters that
+// move funds, so they survive until the whole transaction has been parsed.
+#[derive(Clone, Debug, Default)]
+pub struct MessageSummary {
+    pub amount: Option<ArrayVec<u8, 64>>,
+    pub to_self: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Warning {
+    ZeroAmount,
+    FeeExceedsAmount,
+    SendToSelf,
+    UnknownChainId,
+    SenderMismatch,
+    SignerMismatch,
+    InsecureServiceUrl,
+    IpServiceHost,
+    NoServicePort,
+    NotFullyDecoded,
+}
+
+impl Warning {
+    fn text(self) -> &'static str {
+        match self {
+            Warning::ZeroAmount => "Amount is zero",
+            Warning::FeeExceedsAmount => "Fee is larger than the amount",
+            Warning::SendToSelf => "Recipient is this device's own address",
+            Warning::UnknownChainId => "Unknown chain ID",
+            Warning::SenderMismatch => "Sender is not this device's address",
+            Warning::SignerMismatch => "Signer is not this device's address",
+            Warning::InsecureServiceUrl => "Service URL does not use https",
+            Warning::IpServiceHost => "Service host is an IP address",
+            Warning::NoServicePort => "Service URL has no port",
+            Warning::NotFullyDecoded => "Could not decode all of this transaction; check the hash",
+        }
+    }
+}
+
+// collect_warnings raises each of its four warnings at most once; the others are shown where the
+// field they are about is parsed.
+const MAX_WARNINGS: usize = 4;
+
+pub fn collect_warnings(
+    known_network: bool,
+    fee: Option<&[u8]>,
+    message: Option<&MessageSummary>,
+) -> ArrayVec<Warning, MAX_WARNINGS> {
+    let mut rv = ArrayVec::new();
+    if let Some(amount) = message.and_then(|m| m.amount.as_ref()) {
+        if is_zero(amount) {
+            rv.push(Warning::ZeroAmount);
+        }
+        if let Some(fee) = fee {
+            if compare_amounts(fee, amount) == Ordering::Greater {
+                rv.push(Warning::FeeExceedsAmount);
+            }
+        }
+    }
+    if message.map_or(false, |m| m.to_self) {
+        rv.push(Warning::SendToSelf);
+    }
+    if !known_network {
+        rv.push(Warning::UnknownChainId);
+    }
+    rv
+}
+
+pub fn show_warning(warning: Warning) -> Option<()> {
+    scroller_paginated("WARNING", |w| Ok(write!(w, "{}", warning.text())?))
+}
+
+// One screen per warning, so none of them can be scrolled past as part of another.
+pub fn show_warnings(warnings: &[Warning]) -> Option<()> {
+    for warning in warnings {
+        show_warning(*warning)?;
+    }
+    Some(())
+}
+
+fn trim_leading_zeros(amount: &[u8]) -> &[u8] {
+    let zeros = amount.iter().take_while(|c| **c == b'0').count();
+    &amount[zeros..]
+}
+
+fn is_zero(amount: &[u8]) -> bool {
+    trim_leading_zeros(amount).is_empty()
+}
+
+// Amounts are decimal strings of arbitrary length, so compare them by digit count first.
+fn compare_amounts(a: &[u8], b: &[u8]) -> Ordering {
+    let (a, b) = (trim_leading_zeros(a), trim_leading_zeros(b));
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
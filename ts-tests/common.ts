@@ -16,6 +16,9 @@ const ignoredScreens = [ "Cancel", "Working...", "Quit", "Version"
                          , "Pocket", `${VERSION.major}.${VERSION.minor}.${VERSION.patch}`
 
                          , "Settings", "Blind Signing", "Enabled", "Disabled", "Back"
+                         , "Sender Check", "Reject", "Warn only", "Expert Mode"
+                         , "Blind Sign Expiry", "Never", "After one signature", "On app restart"
+                         , "For one signature", "Until restart"
                          /* The next ones are specifically for S+ in which OCR is broken */
                          , "ettings", "Blind igning"
                        ];
@@ -194,15 +197,72 @@ const sendCommandExpectFail = async function(command : any) {
   expect.fail("Command should have failed");
 }
 
+// Like sendCommandExpectFail, but also checks the status word the app failed with.
+const sendCommandExpectStatus = async function(command : any, statusCode : number) {
+  let actual = null;
+  await sendCommandExpectFail(async (client : Pokt) => {
+    try {
+      await command(client);
+    } catch (e) {
+      actual = e.statusCode;
+      throw e;
+    }
+  });
+  expect(actual).to.equal(statusCode);
+}
+
 let toggleBlindSigningSettings = async function() {
   await Axios.post(BASE_URL + "/button/right", {"action":"press-and-release"});
   await Axios.post(BASE_URL + "/button/right", {"action":"press-and-release"});
   await Axios.post(BASE_URL + "/button/both", {"action":"press-and-release"});
   await Axios.post(BASE_URL + "/button/both", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/left", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/both", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/left", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/left", {"action":"press-and-release"});
+}
+
+let toggleSenderCheckSettings = async function() {
+  await Axios.post(BASE_URL + "/button/right", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/right", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/both", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/right", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/right", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/both", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/left", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/left", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/left", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/both", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/left", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/left", {"action":"press-and-release"});
+}
+
+let toggleExpertModeSettings = async function() {
+  await Axios.post(BASE_URL + "/button/right", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/right", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/both", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/right", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/right", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/right", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/both", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/right", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/both", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/left", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/left", {"action":"press-and-release"});
+}
+
+// Moves blind signing on to the next expiry: never, after one signature, on app restart.
+let cycleBlindSigningExpirySettings = async function() {
+  await Axios.post(BASE_URL + "/button/right", {"action":"press-and-release"});
   await Axios.post(BASE_URL + "/button/right", {"action":"press-and-release"});
   await Axios.post(BASE_URL + "/button/both", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/right", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/both", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/left", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/left", {"action":"press-and-release"});
+  await Axios.post(BASE_URL + "/button/both", {"action":"press-and-release"});
   await Axios.post(BASE_URL + "/button/left", {"action":"press-and-release"});
   await Axios.post(BASE_URL + "/button/left", {"action":"press-and-release"});
 }
 
-export { sendCommandAndAccept, BASE_URL, sendCommandExpectFail, toggleBlindSigningSettings }
+export { sendCommandAndAccept, BASE_URL, sendCommandExpectFail, sendCommandExpectStatus, toggleBlindSigningSettings, toggleSenderCheckSettings, toggleExpertModeSettings, cycleBlindSigningExpirySettings }
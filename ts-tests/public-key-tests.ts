@@ -1,9 +1,19 @@
-import { sendCommandAndAccept, BASE_URL } from "./common";
+import { sendCommandAndAccept, sendCommandExpectStatus, BASE_URL } from "./common";
 import { expect } from 'chai';
 import { describe, it } from 'mocha';
 import Axios from 'axios';
 import Pokt from "hw-app-pokt";
 
+// The parameter of GET_PUBKEYS: the number of paths, then each path as GET_PUBKEY takes it.
+function pubkeysParameter(paths: number[][]) {
+  return Buffer.concat([Buffer.from([paths.length]), ...paths.map((path) => {
+    const encoded = Buffer.alloc(1 + 4 * path.length);
+    encoded.writeUInt8(path.length);
+    path.forEach((step, i) => encoded.writeUInt32LE(step, 1 + 4 * i));
+    return encoded;
+  })]);
+}
+
 describe('public key tests', () => {
 
   afterEach( async function() {
@@ -46,4 +56,37 @@ describe('public key tests', () => {
       },
     ]);
   });
+
+  it('rejects a path outside of the app', async () => {
+    await sendCommandExpectStatus(async (client : Pokt) => {
+      await client.getPublicKey("44'/1'/0");
+    }, 0xb007);
+  });
+
+  it('provides a batch of public keys, over several responses', async () => {
+
+    await sendCommandAndAccept(async (client : Pokt) => {
+      const paths = [0, 1, 2, 3, 4, 5, 6, 7].map((i) => [0x8000002c, 0x8000027b, i]);
+      // Too long for one response, so the app sends it with RESULT_ACCUMULATING.
+      const rv : Buffer = await client.sendChunks(0x00, 0x05, 0x00, 0x00, [pubkeysParameter(paths)]);
+      expect(rv.length).to.equal(8 * 54);
+      expect(rv.slice(1, 33).toString('hex')).to.equal("5a354b0d33de0006376dcb756113ab0fc3dc6e758101bcc9be5b7b538d5ae388");
+      for (let i = 0; i < paths.length; i++) {
+        const single = await client.getPublicKey("44'/635'/" + i);
+        const entry = rv.slice(54 * i, 54 * (i + 1));
+        expect(entry[0]).to.equal(32);
+        expect(entry.slice(1, 33).toString('hex')).to.equal(new Buffer(single.publicKey).toString('hex'));
+        expect(entry[33]).to.equal(20);
+        expect(entry.slice(34).toString('hex')).to.equal(new Buffer(single.address).toString('hex'));
+      }
+      return;
+    }, []);
+  });
+
+  it('rejects a batch with any path outside of the app', async () => {
+    await sendCommandExpectStatus(async (client : Pokt) => {
+      const paths = [[0x8000002c, 0x8000027b, 0], [0x8000002c, 0x80000001, 0]];
+      await client.sendChunks(0x00, 0x05, 0x00, 0x00, [pubkeysParameter(paths)]);
+    }, 0xb007);
+  });
 });
@@ -1,9 +1,43 @@
-import { VERSION, sendCommandAndAccept, BASE_URL, sendCommandExpectFail, toggleBlindSigningSettings } from "./common";
+import { VERSION, sendCommandAndAccept, BASE_URL, sendCommandExpectFail, sendCommandExpectStatus, toggleBlindSigningSettings, toggleSenderCheckSettings, toggleExpertModeSettings, cycleBlindSigningExpirySettings } from "./common";
 import { expect } from 'chai';
 import { describe, it } from 'mocha';
 import Axios from 'axios';
 import Pokt from "hw-app-pokt";
 import * as ed from '@noble/ed25519';
+import { createHash } from 'crypto';
+
+// Every signing flow ends with the hash of the transaction, just before the final prompt.
+function hexGroups(hex: string) {
+  return hex.match(/.{1,4}/g).join(" ");
+}
+
+// Blind signing also shows a short form of the hash and the length after reading the payload once.
+function withTransactionHash(txn: Buffer, prompts: any[]) {
+  const hash = createHash("sha256").update(txn).digest("hex");
+  const i = prompts.findIndex((p: any) => p["text"] == "Sign Transaction?" || p["text"] == "Blind Sign Transaction?");
+  const payload = prompts[i]["text"] == "Blind Sign Transaction?"
+    ? [
+      { "header": "Payload Hash", "prompt": hexGroups(hash.slice(0, 8)) + " ... " + hexGroups(hash.slice(-8)) },
+      { "header": "Payload Length", "prompt": txn.length + " bytes" },
+    ]
+    : [];
+  return [ ...prompts.slice(0, i), ...payload, { "header": "JSON SHA-256", "prompt": hash, "paginate": true }, ...prompts.slice(i) ];
+}
+
+async function expectValidSignature(sig: Buffer, txn: Buffer, publicKey: Buffer) {
+  expect(sig.length).to.equal(64);
+  expect(await ed.verify(sig, txn, publicKey) ? "Signature Valid": "Signature Invalid").to.equal("Signature Valid");
+}
+
+// The parameters of SIGN_TX, for sending it with a P1 that the client library doesn't set.
+function signParameters(path: number[], txn: Buffer) {
+  const txnLength = Buffer.alloc(4);
+  txnLength.writeUInt32LE(txn.length);
+  const pathPayload = Buffer.alloc(1 + 4 * path.length);
+  pathPayload.writeUInt8(path.length);
+  path.forEach((step, i) => pathPayload.writeUInt32LE(step, 1 + 4 * i));
+  return [ Buffer.concat([txnLength, txn]), pathPayload ];
+}
 
 function testTransactionInternal(path: string, txn0: any, blind: boolean, prompts: any[]) {
   const txn = Buffer.from(JSON.stringify(txn0), "utf-8");
@@ -19,15 +53,36 @@ function testTransactionInternal(path: string, txn0: any, blind: boolean, prompt
       // We don't want the prompts from getPublicKey in our result
       await Axios.delete(BASE_URL + "/events");
 
-      const sig = blind
-          ? await client.blindSignTransaction(path, txn)
-          : await client.signTransaction(path, txn);
+      try {
+        const sig = blind
+            ? await client.blindSignTransaction(path, txn)
+            : await client.signTransaction(path, txn);
 
-      expect(await ed.verify(sig.signature, txn, pk.publicKey) ? "Signature Valid": "Signature Invalid").to.equal("Signature Valid");
-    }, prompts);
+        await expectValidSignature(sig.signature, txn, pk.publicKey);
+      } finally {
+        if (blind) {
+          await toggleBlindSigningSettings();
+        }
+      }
+    }, withTransactionHash(txn, prompts));
   }
 }
 
+// Signs a transaction that the app should refuse, and returns the last response: the rejection
+// details followed by the status word.
+async function signExpectingRejection(txn: Buffer, statusCode: number) {
+  let response: Buffer = null;
+  await sendCommandExpectStatus(async (client : Pokt) => {
+    const exchange = client.transport.exchange.bind(client.transport);
+    client.transport.exchange = async (apdu: Buffer) => {
+      response = await exchange(apdu);
+      return response;
+    };
+    await client.signTransaction("44'/635'/0/0", txn);
+  }, statusCode);
+  return response;
+}
+
 const testTransaction = (path: string, txn: any, prompts: any[]) =>
     testTransactionInternal(path, txn, false, prompts);
 
@@ -50,7 +105,7 @@ const exampleSend = {
     "type": "pos/Send",
     "value": {
       "amount": "10000000",
-      "from_address": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+      "from_address": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
       "to_address": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba"
     }
   }
@@ -70,7 +125,7 @@ const exampleSend2 = {
     "type": "pos/Send",
     "value": {
       "amount": "10203040",
-      "from_address": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+      "from_address": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
       "to_address": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba"
     }
   }
@@ -90,7 +145,7 @@ const exampleSend3 = {
     "type": "pos/Send",
     "value": {
       "amount": "002000000000",
-      "from_address": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+      "from_address": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
       "to_address": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba"
     }
   }
@@ -110,7 +165,7 @@ const exampleSend4 = {
     "type": "pos/Send",
     "value": {
       "amount": "10100000",
-      "from_address": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+      "from_address": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
       "to_address": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba"
     }
   }
@@ -129,8 +184,8 @@ const exampleUnjail = {
   "msg": {
     "type": "pos/8.0MsgUnjail",
     "value": {
-      "address": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
-      "signer_address": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8bb"
+      "address": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+      "signer_address": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488"
     }
   }
 };
@@ -199,6 +254,263 @@ const exampleUnstake = {
     }
   ],
   "memo": "",
+  "msg": {
+    "type": "pos/8.0MsgBeginUnstake",
+    "value": {
+      "signer_address": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+      "validator_address": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba"
+    }
+  }
+};
+
+const exampleAppStake = {
+  "chain_id": "testnet",
+  "entropy": "2417661502575469960",
+  "fee": [
+    {
+      "amount": "10000",
+      "denom": "upokt"
+    }
+  ],
+  "memo": "",
+  "msg": {
+    "type": "apps/MsgAppStake",
+    "value": {
+      "chains": [
+        "0021", "0040"
+      ],
+      "pubkey": {
+        "type": "crypto/ed25519_public_key",
+        "value": "6b62a590bab42ea01383d3209fa719254977fb83624fbd6755d102264ba1adc0"
+      },
+      "value": "5000000"
+    }
+  }
+};
+
+const exampleAppUnstake = {
+  "chain_id": "testnet",
+  "entropy": "-1105361304155186876",
+  "fee": [
+    {
+      "amount": "10000",
+      "denom": "upokt"
+    }
+  ],
+  "memo": "",
+  "msg": {
+    "type": "apps/MsgAppBeginUnstake",
+    "value": {
+      "application_address": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba"
+    }
+  }
+};
+
+const exampleAppUnjail = {
+  "chain_id": "testnet",
+  "entropy": "-8051161335943327787",
+  "fee": [
+    {
+      "amount": "10000",
+      "denom": "upokt"
+    }
+  ],
+  "memo": "",
+  "msg": {
+    "type": "apps/MsgAppUnjail",
+    "value": {
+      "address": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba"
+    }
+  }
+};
+
+const exampleDaoTransfer = {
+  "chain_id": "testnet",
+  "entropy": "-3104352719104528196",
+  "fee": [
+    {
+      "amount": "10000",
+      "denom": "upokt"
+    }
+  ],
+  "memo": "",
+  "msg": {
+    "type": "gov/msg_dao_transfer",
+    "value": {
+      "action": "dao_transfer",
+      "amount": "25000000",
+      "from_address": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+      "to_address": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba"
+    }
+  }
+};
+
+const exampleDaoBurn = {
+  "chain_id": "testnet",
+  "entropy": "-3104352719104528197",
+  "fee": [
+    {
+      "amount": "10000",
+      "denom": "upokt"
+    }
+  ],
+  "memo": "",
+  "msg": {
+    "type": "gov/msg_dao_transfer",
+    "value": {
+      "action": "dao_burn",
+      "amount": "1000000",
+      "from_address": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+      "to_address": ""
+    }
+  }
+};
+
+const exampleChangeParam = {
+  "chain_id": "testnet",
+  "entropy": "4218867327413298165",
+  "fee": [
+    {
+      "amount": "10000",
+      "denom": "upokt"
+    }
+  ],
+  "memo": "",
+  "msg": {
+    "type": "gov/msg_change_param",
+    "value": {
+      "address": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+      "param_key": "pos/StakeMinimum",
+      "param_value": "IjE1MDAwMDAwMDAwIg=="
+    }
+  }
+};
+
+const exampleUpgrade = {
+  "chain_id": "testnet",
+  "entropy": "4218867327413298166",
+  "fee": [
+    {
+      "amount": "10000",
+      "denom": "upokt"
+    }
+  ],
+  "memo": "",
+  "msg": {
+    "type": "gov/msg_upgrade",
+    "value": {
+      "address": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+      "upgrade": {
+        "Features": [
+          "RSCAL:95000"
+        ],
+        "Height": "95000",
+        "OldUpgradeHeight": "0",
+        "Version": "RC-0.9.2"
+      }
+    }
+  }
+};
+
+const exampleLegacyStake = {
+  "chain_id": "testnet",
+  "entropy": "2417661502575469961",
+  "fee": [
+    {
+      "amount": "10000",
+      "denom": "upokt"
+    }
+  ],
+  "memo": "",
+  "msg": {
+    "type": "pos/MsgStake",
+    "value": {
+      "chains": [
+        "0034"
+      ],
+      "public_key": {
+        "type": "crypto/ed25519_public_key",
+        "value": "6b62a590bab42ea01383d3209fa719254977fb83624fbd6755d102264ba1adc0"
+      },
+      "service_url": "https://serviceURI.com:3000",
+      "value": "1000000"
+    }
+  }
+};
+
+const exampleStakeWithDelegators = {
+  "chain_id": "testnet",
+  "entropy": "2417661502575469962",
+  "fee": [
+    {
+      "amount": "10000",
+      "denom": "upokt"
+    }
+  ],
+  "memo": "",
+  "msg": {
+    "type": "pos/8.0MsgStake",
+    "value": {
+      "chains": [
+        "0034"
+      ],
+      "public_key": {
+        "type": "crypto/ed25519_public_key",
+        "value": "6b62a590bab42ea01383d3209fa719254977fb83624fbd6755d102264ba1adc0"
+      },
+      "service_url": "https://serviceURI.com:3000",
+      "value": "1000000",
+      "output_address":"db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+      "reward_delegators": {
+        "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8bb": 10,
+        "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8bc": 5
+      }
+    }
+  }
+};
+
+const exampleSendUnknownChain = { ...exampleSend, "chain_id": "localnet" };
+
+const exampleSendTwoFees = {
+  ...exampleSend,
+  "fee": [
+    {
+      "amount": "10000",
+      "denom": "upokt"
+    },
+    {
+      "amount": "2500",
+      "denom": "upokt"
+    }
+  ],
+};
+
+const exampleSendZeroToSelf = {
+  ...exampleSend,
+  "msg": {
+    "type": "pos/Send",
+    "value": {
+      "amount": "0",
+      "from_address": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+      "to_address": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488"
+    }
+  }
+};
+
+const exampleSendOtherSender = {
+  ...exampleSend,
+  "msg": {
+    "type": "pos/Send",
+    "value": {
+      "amount": "10000000",
+      "from_address": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+      "to_address": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba"
+    }
+  }
+};
+
+const exampleUnstakeOtherSigner = {
+  ...exampleUnstake,
   "msg": {
     "type": "pos/8.0MsgBeginUnstake",
     "value": {
@@ -208,6 +520,54 @@ const exampleUnstake = {
   }
 };
 
+const exampleStakeManyChains = {
+  ...exampleStake,
+  "msg": {
+    ...exampleStake.msg,
+    "value": {
+      ...exampleStake.msg.value,
+      "chains": [
+        "0001", "0003", "0004", "0005", "0006", "0009", "0021", "0022"
+      ]
+    }
+  }
+};
+
+const exampleStakeInsecureUrl = {
+  ...exampleStake,
+  "msg": {
+    ...exampleStake.msg,
+    "value": {
+      ...exampleStake.msg.value,
+      "service_url": "http://10.0.0.1"
+    }
+  }
+};
+
+const exampleSendUnknownType = {
+  ...exampleSend,
+  "msg": {
+    "type": "pos/MsgFutureThing",
+    "value": {
+      "whatever": [1, 2, 3]
+    }
+  }
+};
+
+const exampleSendUnknownFields = {
+  ...exampleSend,
+  "msg": {
+    "type": "pos/MsgFutureThing",
+    "value": {
+      "owner": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+      "whatever": [1, 2, 3]
+    }
+  }
+};
+
+// As long a memo as pocket-core allows.
+const exampleSendLongMemo = { ...exampleSend, "memo": "0123456789".repeat(7) + "abcde" };
+
 describe("Signing tests", function() {
   it("can sign a simple transfer",
      testTransaction(
@@ -220,7 +580,7 @@ describe("Signing tests", function() {
          },
          {
            "header": "From",
-           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
            "paginate": true,
          },
          {
@@ -236,6 +596,15 @@ describe("Signing tests", function() {
            "header": "Fee",
            "prompt": "POKT 0.012",
          },
+         {
+           "header": "Memo",
+           "prompt": "Fourth transaction",
+           "paginate": true,
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
          {
            "text": "Sign Transaction?",
            "x": 19,
@@ -259,7 +628,7 @@ describe("Signing tests", function() {
          },
          {
            "header": "From",
-           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
            "paginate": true,
          },
          {
@@ -275,6 +644,15 @@ describe("Signing tests", function() {
            "header": "Fee",
            "prompt": "POKT 0.000002",
          },
+         {
+           "header": "Memo",
+           "prompt": "Fourth transaction",
+           "paginate": true,
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
          {
            "text": "Sign Transaction?",
            "x": 19,
@@ -298,7 +676,7 @@ describe("Signing tests", function() {
          },
          {
            "header": "From",
-           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
            "paginate": true,
          },
          {
@@ -314,6 +692,15 @@ describe("Signing tests", function() {
            "header": "Fee",
            "prompt": "POKT 0.01",
          },
+         {
+           "header": "Memo",
+           "prompt": "Fourth transaction",
+           "paginate": true,
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
          {
            "text": "Sign Transaction?",
            "x": 19,
@@ -337,7 +724,7 @@ describe("Signing tests", function() {
          },
          {
            "header": "From",
-           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
            "paginate": true,
          },
          {
@@ -353,6 +740,15 @@ describe("Signing tests", function() {
            "header": "Fee",
            "prompt": "POKT 0.012",
          },
+         {
+           "header": "Memo",
+           "prompt": "Fourth transaction",
+           "paginate": true,
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
          {
            "text": "Sign Transaction?",
            "x": 19,
@@ -376,16 +772,24 @@ describe("Signing tests", function() {
         },
         {
           "header": "Address",
-          "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba"
+          "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488"
         },
         {
-          "header": "Signer address",
-          "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8bb"
+          "header": "Signer",
+          "prompt": "this device (operator)"
         },
         {
           "header": "Fee",
           "prompt": "POKT 0.01",
         },
+        {
+          "header": "No memo",
+          "prompt": "",
+        },
+        {
+          "header": "Network",
+          "prompt": "Pocket Testnet",
+        },
         {
           "text": "Sign Transaction?",
           "x": 19,
@@ -408,6 +812,14 @@ describe("Signing tests", function() {
            "header": "Stake",
            "prompt": "POKT",
          },
+         {
+           "header": "Chain 1",
+           "prompt": "0034: UNKNOWN",
+         },
+         {
+           "header": "Total chains",
+           "prompt": "1",
+         },
          {
            "header": "From",
            "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
@@ -421,6 +833,11 @@ describe("Signing tests", function() {
            "header": "Node Operator",
            "prompt": "6b62a590bab42ea01383d3209fa719254977fb83624fbd6755d102264ba1adc0 (crypto/ed25519_public_key)",
          },
+         {
+           "header": "Operator Address",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+           "paginate": true,
+         },
          {
            "header": "Output Address",
            "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
@@ -430,13 +847,22 @@ describe("Signing tests", function() {
            "prompt": "https://serviceURI.com:3000",
          },
          {
-           "header": "Chain ID",
-           "prompt": "0034",
+           "header": "Service Host",
+           "prompt": "serviceURI.com",
+           "paginate": true,
          },
          {
            "header": "Fee",
            "prompt": "POKT 0.01",
          },
+         {
+           "header": "No memo",
+           "prompt": "",
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
          {
            "text": "Sign Transaction?",
            "x": 19,
@@ -460,6 +886,26 @@ describe("Signing tests", function() {
            "header": "Stake",
            "prompt": "POKT",
          },
+         {
+           "header": "Chain 1",
+           "prompt": "0011: UNKNOWN",
+         },
+         {
+           "header": "Chain 2",
+           "prompt": "0022: Ethereum Archival",
+         },
+         {
+           "header": "Chain 3",
+           "prompt": "0033: UNKNOWN",
+         },
+         {
+           "header": "Chain 4",
+           "prompt": "0044: IoTeX",
+         },
+         {
+           "header": "Total chains",
+           "prompt": "4",
+         },
          {
            "header": "From",
            "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
@@ -473,6 +919,11 @@ describe("Signing tests", function() {
            "header": "Node Operator",
            "prompt": "6b62a590bab42ea01383d3209fa719254977fb83624fbd6755d102264ba1adc0 (crypto/ed25519_public_key)",
          },
+         {
+           "header": "Operator Address",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+           "paginate": true,
+         },
          {
            "header": "Output Address",
            "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
@@ -482,24 +933,21 @@ describe("Signing tests", function() {
            "prompt": "https://serviceURI.com:3000",
          },
          {
-           "header": "Chain ID (1/4)",
-           "prompt": "0011",
-         },
-         {
-           "header": "Chain ID (2/4)",
-           "prompt": "0022",
+           "header": "Service Host",
+           "prompt": "serviceURI.com",
+           "paginate": true,
          },
          {
-           "header": "Chain ID (3/4)",
-           "prompt": "0033",
+           "header": "Fee",
+           "prompt": "POKT 0.01",
          },
          {
-           "header": "Chain ID (4/4)",
-           "prompt": "0044",
+           "header": "No memo",
+           "prompt": "",
          },
          {
-           "header": "Fee",
-           "prompt": "POKT 0.01",
+           "header": "Network",
+           "prompt": "Pocket Testnet",
          },
          {
            "text": "Sign Transaction?",
@@ -525,8 +973,8 @@ describe("Signing tests", function() {
           "prompt": "POKT"
         },
         {
-          "header": "Signer address",
-          "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8bb"
+          "header": "Signer",
+          "prompt": "this device (not operator)"
         },
         {
           "header": "Unstake address",
@@ -536,6 +984,14 @@ describe("Signing tests", function() {
           "header": "Fee",
           "prompt": "POKT 0.01",
         },
+        {
+          "header": "No memo",
+          "prompt": "",
+        },
+        {
+          "header": "Network",
+          "prompt": "Pocket Testnet",
+        },
         {
           "text": "Sign Transaction?",
           "x": 19,
@@ -548,69 +1004,1593 @@ describe("Signing tests", function() {
         }
        ]
      ));
-});
-
-function testBlindSignFail(path: string, hash: string) {
-  return async () => {
-    await sendCommandExpectFail(
-      async (client : Pokt) => {
-        await client.blindSignTransaction(path, hash);
-      });
-  }
-}
-
-function testBlindSignFail2(path: string, hash: string) {
-  return async () => {
-    await sendCommandExpectFail(
-      async (client : Pokt) => {
-        // Enable and then disable
-        await toggleBlindSigningSettings();
-        await toggleBlindSigningSettings();
-        await Axios.delete(BASE_URL + "/events");
-        await client.blindSignTransaction(path, hash);
-      });
-  }
-}
 
-describe("Blind signing tests", function() {
+  it("can sign an app stake",
+     testTransaction(
+       "44'/635'/0/0",
+       exampleAppStake,
+       [
+         {
+           "header": "App Stake",
+           "prompt": "POKT",
+         },
+         {
+           "header": "Chain 1",
+           "prompt": "0021: Ethereum",
+         },
+         {
+           "header": "Chain 2",
+           "prompt": "0040: Harmony Shard 0",
+         },
+         {
+           "header": "Total chains",
+           "prompt": "2",
+         },
+         {
+           "header": "Signer",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+           "paginate": true,
+         },
+         {
+           "header": "Amount",
+           "prompt": "POKT 5.0",
+         },
+         {
+           "header": "App Public Key",
+           "prompt": "6b62a590bab42ea01383d3209fa719254977fb83624fbd6755d102264ba1adc0 (crypto/ed25519_public_key)",
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.01",
+         },
+         {
+           "header": "No memo",
+           "prompt": "",
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
+         {
+           "text": "Sign Transaction?",
+           "x": 19,
+           "y": 11
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
+           "y": 11,
+         },
+       ]
+     ));
 
-  it("cannot sign arbitary JSON without settings enabled",
-     testBlindSignFail(
+  it("can sign an app unstake",
+     testTransaction(
        "44'/635'/0/0",
-       '"ffd8cd79deb956fa3c7d9be0f836f20ac84b140168a087a842be4760e40e2b1c"'
+       exampleAppUnstake,
+       [
+        {
+          "header": "App Unstake",
+          "prompt": "POKT"
+        },
+        {
+          "header": "App address",
+          "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba"
+        },
+        {
+          "header": "Fee",
+          "prompt": "POKT 0.01",
+        },
+        {
+          "header": "No memo",
+          "prompt": "",
+        },
+        {
+          "header": "Network",
+          "prompt": "Pocket Testnet",
+        },
+        {
+          "text": "Sign Transaction?",
+          "x": 19,
+          "y": 11
+        },
+        {
+          "text": "Confirm",
+          "x": 43,
+          "y": 11
+        }
+       ]
      ));
-  it("cannot sign abitrary JSON without settings enabled 2",
-     testBlindSignFail2(
+
+  it("can sign an app unjail",
+     testTransaction(
        "44'/635'/0/0",
-       '"ffd8cd79deb956fa3c7d9be0f836f20ac84b140168a087a842be4760e40e2b1c"'
+       exampleAppUnjail,
+       [
+        {
+          "header": "App Unjail",
+          "prompt": "Transaction"
+        },
+        {
+          "header": "App address",
+          "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba"
+        },
+        {
+          "header": "Fee",
+          "prompt": "POKT 0.01",
+        },
+        {
+          "header": "No memo",
+          "prompt": "",
+        },
+        {
+          "header": "Network",
+          "prompt": "Pocket Testnet",
+        },
+        {
+          "text": "Sign Transaction?",
+          "x": 19,
+          "y": 11
+        },
+        {
+          "text": "Confirm",
+          "x": 43,
+          "y": 11
+        }
+       ]
      ));
 
-  it("can blind sign nonsense JSON",
-     testBlindTransaction(
+  it("can sign a DAO transfer",
+     testTransaction(
        "44'/635'/0/0",
-       {
-         foo: 1,
-         bar: null,
-       },
+       exampleDaoTransfer,
        [
          {
-           "header": "WARNING",
-           "prompt": "Blind Signing a Transaction is a very unusual operation. Do not continue unless you know what you are doing",
+           "header": "DAO Treasury",
+           "prompt": "POKT",
          },
          {
-           "header": "Sign for Address",
+           "header": "Action",
+           "prompt": "Transfer",
+         },
+         {
+           "header": "From",
            "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+           "paginate": true,
          },
          {
-           "text": "Blind Sign Transaction?",
-           "x": 4,
+           "header": "To",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+           "paginate": true,
+         },
+         {
+           "header": "Amount",
+           "prompt": "POKT 25.0",
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.01",
+         },
+         {
+           "header": "No memo",
+           "prompt": "",
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
+         {
+           "text": "Sign Transaction?",
+           "x": 19,
+           "y": 11
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
            "y": 11,
          },
+       ]
+     ));
+
+  it("can sign a DAO burn",
+     testTransaction(
+       "44'/635'/0/0",
+       exampleDaoBurn,
+       [
+         {
+           "header": "DAO Treasury",
+           "prompt": "POKT",
+         },
+         {
+           "header": "Action",
+           "prompt": "Burn",
+         },
+         {
+           "header": "From",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+           "paginate": true,
+         },
+         {
+           "header": "Amount",
+           "prompt": "POKT 1.0",
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.01",
+         },
+         {
+           "header": "No memo",
+           "prompt": "",
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
+         {
+           "text": "Sign Transaction?",
+           "x": 19,
+           "y": 11
+         },
          {
            "text": "Confirm",
            "x": 43,
            "y": 11,
-         }
+         },
        ]
      ));
+
+  it("can sign a governance parameter change",
+     testTransaction(
+       "44'/635'/0/0",
+       exampleChangeParam,
+       [
+         {
+           "header": "Change Param",
+           "prompt": "Governance",
+         },
+         {
+           "header": "Address",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+           "paginate": true,
+         },
+         {
+           "header": "Parameter",
+           "prompt": "pos/StakeMinimum",
+           "paginate": true,
+         },
+         {
+           "header": "Value",
+           "prompt": "\"15000000000\"",
+           "paginate": true,
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.01",
+         },
+         {
+           "header": "No memo",
+           "prompt": "",
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
+         {
+           "text": "Sign Transaction?",
+           "x": 19,
+           "y": 11
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
+           "y": 11,
+         },
+       ]
+     ));
+
+  it("can sign a protocol upgrade",
+     testTransaction(
+       "44'/635'/0/0",
+       exampleUpgrade,
+       [
+         {
+           "header": "Upgrade",
+           "prompt": "Protocol",
+         },
+         {
+           "header": "Address",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+         },
+         {
+           "header": "Feature",
+           "prompt": "RSCAL:95000",
+         },
+         {
+           "header": "Height",
+           "prompt": "95000",
+         },
+         {
+           "header": "Version",
+           "prompt": "RC-0.9.2",
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.01",
+         },
+         {
+           "header": "No memo",
+           "prompt": "",
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
+         {
+           "text": "Sign Transaction?",
+           "x": 19,
+           "y": 11
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
+           "y": 11,
+         },
+       ]
+     ));
+
+  it("can sign a legacy stake without an output address",
+     testTransaction(
+       "44'/635'/0/0",
+       exampleLegacyStake,
+       [
+         {
+           "header": "Legacy format",
+           "prompt": "Pre-8.0 message",
+         },
+         {
+           "header": "Stake",
+           "prompt": "POKT",
+         },
+         {
+           "header": "Chain 1",
+           "prompt": "0034: UNKNOWN",
+         },
+         {
+           "header": "Total chains",
+           "prompt": "1",
+         },
+         {
+           "header": "From",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+           "paginate": true,
+         },
+         {
+           "header": "Amount",
+           "prompt": "POKT 1.0",
+         },
+         {
+           "header": "Node Operator",
+           "prompt": "6b62a590bab42ea01383d3209fa719254977fb83624fbd6755d102264ba1adc0 (crypto/ed25519_public_key)",
+         },
+         {
+           "header": "Operator Address",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+           "paginate": true,
+         },
+         {
+           "header": "Service URL",
+           "prompt": "https://serviceURI.com:3000",
+         },
+         {
+           "header": "Service Host",
+           "prompt": "serviceURI.com",
+           "paginate": true,
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.01",
+         },
+         {
+           "header": "No memo",
+           "prompt": "",
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
+         {
+           "text": "Sign Transaction?",
+           "x": 19,
+           "y": 11
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
+           "y": 11,
+         },
+       ]
+     ));
+
+  it("can sign a stake with reward delegators",
+     testTransaction(
+       "44'/635'/0/0",
+       exampleStakeWithDelegators,
+       [
+         {
+           "header": "Stake",
+           "prompt": "POKT",
+         },
+         {
+           "header": "Chain 1",
+           "prompt": "0034: UNKNOWN",
+         },
+         {
+           "header": "Total chains",
+           "prompt": "1",
+         },
+         {
+           "header": "Delegator 1",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8bb (10%)",
+           "paginate": true,
+         },
+         {
+           "header": "Delegator 2",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8bc (5%)",
+           "paginate": true,
+         },
+         {
+           "header": "From",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+           "paginate": true,
+         },
+         {
+           "header": "Amount",
+           "prompt": "POKT 1.0",
+         },
+         {
+           "header": "Node Operator",
+           "prompt": "6b62a590bab42ea01383d3209fa719254977fb83624fbd6755d102264ba1adc0 (crypto/ed25519_public_key)",
+         },
+         {
+           "header": "Operator Address",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+           "paginate": true,
+         },
+         {
+           "header": "Output Address",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+         },
+         {
+           "header": "Service URL",
+           "prompt": "https://serviceURI.com:3000",
+         },
+         {
+           "header": "Service Host",
+           "prompt": "serviceURI.com",
+           "paginate": true,
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.01",
+         },
+         {
+           "header": "No memo",
+           "prompt": "",
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
+         {
+           "text": "Sign Transaction?",
+           "x": 19,
+           "y": 11
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
+           "y": 11,
+         },
+       ]
+     ));
+
+  it("rejects a stake whose delegator shares exceed 100%",
+     async () => {
+       await sendCommandExpectFail(async (client : Pokt) => {
+         const txn = JSON.parse(JSON.stringify(exampleStakeWithDelegators));
+         txn.msg.value.reward_delegators["db987ccfa2a71b2ec9a56c88c77a7cf66d01d8bc"] = 95;
+         await client.signTransaction("44'/635'/0/0", Buffer.from(JSON.stringify(txn), "utf-8"));
+       });
+     });
+
+  it("warns about an unknown chain ID",
+     testTransaction(
+       "44'/635'/0/0",
+       exampleSendUnknownChain,
+       [
+         {
+           "header": "Transfer",
+           "prompt": "POKT",
+         },
+         {
+           "header": "From",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+           "paginate": true,
+         },
+         {
+           "header": "To",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+           "paginate": true,
+         },
+         {
+           "header": "Amount",
+           "prompt": "POKT 10.0",
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.012",
+         },
+         {
+           "header": "Memo",
+           "prompt": "Fourth transaction",
+           "paginate": true,
+         },
+         {
+           "header": "Network",
+           "prompt": "localnet",
+           "paginate": true,
+         },
+         {
+           "header": "WARNING",
+           "prompt": "Unknown chain ID",
+           "paginate": true,
+         },
+         {
+           "text": "Sign Transaction?",
+           "x": 19,
+           "y": 11
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
+           "y": 11,
+         }
+       ]
+     ));
+
+  it("sums multiple fee entries",
+     testTransaction(
+       "44'/635'/0/0",
+       exampleSendTwoFees,
+       [
+         {
+           "header": "Transfer",
+           "prompt": "POKT",
+         },
+         {
+           "header": "From",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+           "paginate": true,
+         },
+         {
+           "header": "To",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+           "paginate": true,
+         },
+         {
+           "header": "Amount",
+           "prompt": "POKT 10.0",
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.0125",
+         },
+         {
+           "header": "Memo",
+           "prompt": "Fourth transaction",
+           "paginate": true,
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
+         {
+           "text": "Sign Transaction?",
+           "x": 19,
+           "y": 11
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
+           "y": 11,
+         }
+       ]
+     ));
+
+  it("rejects a fee in a denomination other than upokt",
+     async () => {
+       const txn = { ...exampleSend, "fee": [ { "amount": "12000", "denom": "uatom" } ] };
+       const response = await signExpectingRejection(Buffer.from(JSON.stringify(txn), "utf-8"), 0xb009);
+       expect(response[0]).to.equal(0); // Refused
+     });
+
+  it("warns about a zero-amount transfer to the device's own address",
+     testTransaction(
+       "44'/635'/0/0",
+       exampleSendZeroToSelf,
+       [
+         {
+           "header": "Transfer",
+           "prompt": "POKT",
+         },
+         {
+           "header": "From",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+           "paginate": true,
+         },
+         {
+           "header": "To",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+           "paginate": true,
+         },
+         {
+           "header": "Amount",
+           "prompt": "POKT 0.000000",
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.012",
+         },
+         {
+           "header": "Memo",
+           "prompt": "Fourth transaction",
+           "paginate": true,
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
+         {
+           "header": "WARNING",
+           "prompt": "Amount is zero",
+           "paginate": true,
+         },
+         {
+           "header": "WARNING",
+           "prompt": "Fee is larger than the amount",
+           "paginate": true,
+         },
+         {
+           "header": "WARNING",
+           "prompt": "Recipient is this device's own address",
+           "paginate": true,
+         },
+         {
+           "text": "Sign Transaction?",
+           "x": 19,
+           "y": 11
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
+           "y": 11,
+         }
+       ]
+     ));
+
+  it("rejects a transfer from an address other than the signing key",
+     async () => {
+       const txnBuf = Buffer.from(JSON.stringify(exampleSendOtherSender), "utf-8");
+       const response = await signExpectingRejection(txnBuf, 0xb00a);
+       expect(response[0]).to.equal(0); // Refused
+       expect(response.slice(6, -2).toString()).to.equal("msg.value");
+     });
+
+  it("warns about a transfer from another address when the sender check is relaxed",
+     async () => {
+       const txn = Buffer.from(JSON.stringify(exampleSendOtherSender), "utf-8");
+       await sendCommandAndAccept(async (client : Pokt) => {
+         const pk = await client.getPublicKey("44'/635'/0/0");
+         await toggleSenderCheckSettings();
+         await Axios.delete(BASE_URL + "/events");
+         try {
+           const sig = await client.signTransaction("44'/635'/0/0", txn);
+           await expectValidSignature(sig.signature, txn, pk.publicKey);
+         } finally {
+           await toggleSenderCheckSettings();
+         }
+       }, withTransactionHash(txn, [
+         {
+           "header": "Transfer",
+           "prompt": "POKT",
+         },
+         {
+           "header": "From",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+           "paginate": true,
+         },
+         {
+           "header": "WARNING",
+           "prompt": "Sender is not this device's address",
+           "paginate": true,
+         },
+         {
+           "header": "To",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+           "paginate": true,
+         },
+         {
+           "header": "Amount",
+           "prompt": "POKT 10.0",
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.012",
+         },
+         {
+           "header": "Memo",
+           "prompt": "Fourth transaction",
+           "paginate": true,
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
+         {
+           "text": "Sign Transaction?",
+           "x": 19,
+           "y": 11
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
+           "y": 11,
+         }
+       ]));
+     });
+
+  it("warns when the unstake signer is not this device",
+     testTransaction(
+       "44'/635'/0/0",
+       exampleUnstakeOtherSigner,
+       [
+        {
+          "header": "Unstake",
+          "prompt": "POKT"
+        },
+        {
+          "header": "Signer address",
+          "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8bb"
+        },
+        {
+          "header": "WARNING",
+          "prompt": "Signer is not this device's address",
+          "paginate": true,
+        },
+        {
+          "header": "Unstake address",
+          "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba"
+        },
+        {
+          "header": "Fee",
+          "prompt": "POKT 0.01",
+        },
+        {
+          "header": "No memo",
+          "prompt": "",
+        },
+        {
+          "header": "Network",
+          "prompt": "Pocket Testnet",
+        },
+        {
+          "text": "Sign Transaction?",
+          "x": 19,
+          "y": 11
+        },
+        {
+          "text": "Confirm",
+          "x": 43,
+          "y": 11
+        }
+       ]
+     ));
+
+  it("rejects a stake whose public key is not an ed25519 key",
+     async () => {
+       const txn = JSON.parse(JSON.stringify(exampleStake));
+       txn.msg.value.public_key.type = "crypto/secp256k1_public_key";
+       const response = await signExpectingRejection(Buffer.from(JSON.stringify(txn), "utf-8"), 0xb009);
+       expect(response[0]).to.equal(0); // Refused
+     });
+
+  it("rejects a stake whose public key is not 32 bytes",
+     async () => {
+       const txn = JSON.parse(JSON.stringify(exampleStake));
+       txn.msg.value.public_key.value = "6b62a590bab42ea01383d3209fa719254977fb83624fbd6755d102264ba1ad";
+       const response = await signExpectingRejection(Buffer.from(JSON.stringify(txn), "utf-8"), 0xb009);
+       expect(response[0]).to.equal(0); // Refused
+     });
+
+  it("can sign a stake with more chains than fit in memory at once",
+     testTransaction(
+       "44'/635'/0/0",
+       exampleStakeManyChains,
+       [
+         {
+           "header": "Stake",
+           "prompt": "POKT",
+         },
+         {
+           "header": "Chain 1",
+           "prompt": "0001: Pocket Network",
+         },
+         {
+           "header": "Chain 2",
+           "prompt": "0003: Avalanche",
+         },
+         {
+           "header": "Chain 3",
+           "prompt": "0004: BNB Smart Chain",
+         },
+         {
+           "header": "Chain 4",
+           "prompt": "0005: Fuse",
+         },
+         {
+           "header": "Chain 5",
+           "prompt": "0006: Solana",
+         },
+         {
+           "header": "Chain 6",
+           "prompt": "0009: Polygon",
+         },
+         {
+           "header": "Chain 7",
+           "prompt": "0021: Ethereum",
+         },
+         {
+           "header": "Chain 8",
+           "prompt": "0022: Ethereum Archival",
+         },
+         {
+           "header": "Total chains",
+           "prompt": "8",
+         },
+         {
+           "header": "From",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+           "paginate": true,
+         },
+         {
+           "header": "Amount",
+           "prompt": "POKT 1.0",
+         },
+         {
+           "header": "Node Operator",
+           "prompt": "6b62a590bab42ea01383d3209fa719254977fb83624fbd6755d102264ba1adc0 (crypto/ed25519_public_key)",
+         },
+         {
+           "header": "Operator Address",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+           "paginate": true,
+         },
+         {
+           "header": "Output Address",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+         },
+         {
+           "header": "Service URL",
+           "prompt": "https://serviceURI.com:3000",
+         },
+         {
+           "header": "Service Host",
+           "prompt": "serviceURI.com",
+           "paginate": true,
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.01",
+         },
+         {
+           "header": "No memo",
+           "prompt": "",
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
+         {
+           "text": "Sign Transaction?",
+           "x": 19,
+           "y": 11
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
+           "y": 11,
+         },
+       ]
+     ));
+
+  it("warns about a plain-http service URL with an IP address and no port",
+     testTransaction(
+       "44'/635'/0/0",
+       exampleStakeInsecureUrl,
+       [
+         {
+           "header": "Stake",
+           "prompt": "POKT",
+         },
+         {
+           "header": "Chain 1",
+           "prompt": "0034: UNKNOWN",
+         },
+         {
+           "header": "Total chains",
+           "prompt": "1",
+         },
+         {
+           "header": "From",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+           "paginate": true,
+         },
+         {
+           "header": "Amount",
+           "prompt": "POKT 1.0",
+         },
+         {
+           "header": "Node Operator",
+           "prompt": "6b62a590bab42ea01383d3209fa719254977fb83624fbd6755d102264ba1adc0 (crypto/ed25519_public_key)",
+         },
+         {
+           "header": "Operator Address",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+           "paginate": true,
+         },
+         {
+           "header": "Output Address",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+         },
+         {
+           "header": "Service URL",
+           "prompt": "http://10.0.0.1",
+         },
+         {
+           "header": "Service Host",
+           "prompt": "10.0.0.1",
+           "paginate": true,
+         },
+         {
+           "header": "WARNING",
+           "prompt": "Service URL does not use https",
+           "paginate": true,
+         },
+         {
+           "header": "WARNING",
+           "prompt": "Service host is an IP address",
+           "paginate": true,
+         },
+         {
+           "header": "WARNING",
+           "prompt": "Service URL has no port",
+           "paginate": true,
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.01",
+         },
+         {
+           "header": "No memo",
+           "prompt": "",
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
+         {
+           "text": "Sign Transaction?",
+           "x": 19,
+           "y": 11
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
+           "y": 11,
+         },
+       ]
+     ));
+
+  it("rejects a service URL that is not http or https",
+     async () => {
+       await sendCommandExpectFail(async (client : Pokt) => {
+         const txn = JSON.parse(JSON.stringify(exampleStake));
+         txn.msg.value.service_url = "ftp://serviceURI.com:3000";
+         await client.signTransaction("44'/635'/0/0", Buffer.from(JSON.stringify(txn), "utf-8"));
+       });
+     });
+
+  it("rejects a service URL whose port is out of range",
+     async () => {
+       await sendCommandExpectFail(async (client : Pokt) => {
+         const txn = JSON.parse(JSON.stringify(exampleStake));
+         txn.msg.value.service_url = "https://serviceURI.com:99999";
+         await client.signTransaction("44'/635'/0/0", Buffer.from(JSON.stringify(txn), "utf-8"));
+       });
+     });
+
+  it("shows every field in expert mode",
+     async () => {
+       const txn = Buffer.from(JSON.stringify(exampleSend), "utf-8");
+       await sendCommandAndAccept(async (client : Pokt) => {
+         const pk = await client.getPublicKey("44'/635'/0/0");
+         await toggleExpertModeSettings();
+         await Axios.delete(BASE_URL + "/events");
+         try {
+           const sig = await client.signTransaction("44'/635'/0/0", txn);
+           await expectValidSignature(sig.signature, txn, pk.publicKey);
+         } finally {
+           await toggleExpertModeSettings();
+         }
+       }, withTransactionHash(txn, [
+         {
+           "header": "Fee Entry",
+           "prompt": "12000 upokt",
+         },
+         {
+           "header": "Message Type",
+           "prompt": "pos/Send",
+           "paginate": true,
+         },
+         {
+           "header": "Transfer",
+           "prompt": "POKT",
+         },
+         {
+           "header": "From",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+           "paginate": true,
+         },
+         {
+           "header": "To",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+           "paginate": true,
+         },
+         {
+           "header": "Amount",
+           "prompt": "POKT 10.0",
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.012",
+         },
+         {
+           "header": "Memo",
+           "prompt": "Fourth transaction",
+           "paginate": true,
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
+         {
+           "header": "Entropy",
+           "prompt": "-7780543831205109370",
+           "paginate": true,
+         },
+         {
+           "text": "Sign Transaction?",
+           "x": 19,
+           "y": 11
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
+           "y": 11,
+         }
+       ]));
+     });
+
+  it("refuses to clear sign an unknown message type",
+     async () => {
+       await sendCommandExpectStatus(async (client : Pokt) => {
+         await client.signTransaction("44'/635'/0/0", Buffer.from(JSON.stringify(exampleSendUnknownType), "utf-8"));
+       }, 0xb005);
+     });
+
+  it("reports where a transaction was rejected",
+     async () => {
+       const txn = JSON.parse(JSON.stringify(exampleSend));
+       txn.msg.value.to_address = "ab".repeat(50);
+       const txnBuf = Buffer.from(JSON.stringify(txn), "utf-8");
+       const response = await signExpectingRejection(txnBuf, 0xb006);
+       expect(response[0]).to.equal(4); // Too long
+       expect(response[1]).to.equal(0); // The transaction parameter
+       expect(response.readUInt32LE(2)).to.be.above(txnBuf.indexOf("to_address"));
+       expect(response.slice(6, -2).toString()).to.equal("msg.value.to_address");
+     });
+
+  it("reports a value of the wrong type as invalid input",
+     async () => {
+       const txn = JSON.parse(JSON.stringify(exampleSend));
+       txn.msg.value.to_address = 12;
+       const txnBuf = Buffer.from(JSON.stringify(txn), "utf-8");
+       const response = await signExpectingRejection(txnBuf, 0xb009);
+       expect(response[0]).to.equal(2); // Invalid value
+       expect(response.slice(6, -2).toString()).to.equal("msg.value.to_address");
+     });
+
+  it("reports an unknown field as such",
+     async () => {
+       const txn = JSON.parse(JSON.stringify(exampleSend));
+       txn.msg.value.extra = "1";
+       const txnBuf = Buffer.from(JSON.stringify(txn), "utf-8");
+       const response = await signExpectingRejection(txnBuf, 0xb009);
+       expect(response[0]).to.equal(5); // Unknown field
+       expect(response.slice(6, -2).toString()).to.equal("msg.value.extra");
+     });
+
+  it("cuts a long field path short",
+     async () => {
+       const txn = JSON.parse(JSON.stringify(exampleStakeWithDelegators));
+       txn.msg.value.reward_delegators["db987ccfa2a71b2ec9a56c88c77a7cf66d01d8bc"] = 95;
+       const txnBuf = Buffer.from(JSON.stringify(txn), "utf-8");
+       const response = await signExpectingRejection(txnBuf, 0xb009);
+       expect(response[0]).to.equal(2); // Invalid value
+       const field = response.slice(6, -2).toString();
+       expect(field.length).to.equal(32);
+       expect(field).to.equal("msg.value.reward_delegators.d...");
+     });
+
+  it("reports an unknown message type as such",
+     async () => {
+       const txnBuf = Buffer.from(JSON.stringify(exampleSendUnknownType), "utf-8");
+       const response = await signExpectingRejection(txnBuf, 0xb005);
+       expect(response[0]).to.equal(3); // Unknown type
+       expect(response.slice(6, -2).toString()).to.equal("msg.type");
+     });
+
+  it("can sign a transfer with the longest memo pocket-core allows",
+     testTransaction(
+       "44'/635'/0/0",
+       exampleSendLongMemo,
+       [
+         {
+           "header": "Transfer",
+           "prompt": "POKT",
+         },
+         {
+           "header": "From",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+           "paginate": true,
+         },
+         {
+           "header": "To",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+           "paginate": true,
+         },
+         {
+           "header": "Amount",
+           "prompt": "POKT 10.0",
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.012",
+         },
+         {
+           "header": "Memo",
+           "prompt": "0123456789".repeat(7) + "abcde",
+           "paginate": true,
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
+         {
+           "text": "Sign Transaction?",
+           "x": 19,
+           "y": 11
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
+           "y": 11,
+         }
+       ]
+     ));
+
+  it("returns the transaction hash after the signature when asked to",
+     async () => {
+       const txn = Buffer.from(JSON.stringify(exampleSend), "utf-8");
+       await sendCommandAndAccept(async (client : Pokt) => {
+         const pk = await client.getPublicKey("44'/635'/0/0");
+         await Axios.delete(BASE_URL + "/events");
+         const rv = await client.sendChunks(0x00, 0x03, 0x01, 0x00, signParameters([0x8000002c, 0x8000027b, 0, 0], txn));
+         expect(rv.length).to.equal(96);
+         await expectValidSignature(rv.slice(0, 64), txn, pk.publicKey);
+         expect(rv.slice(64).toString("hex")).to.equal(createHash("sha256").update(txn).digest("hex"));
+       }, withTransactionHash(txn, [
+         {
+           "header": "Transfer",
+           "prompt": "POKT",
+         },
+         {
+           "header": "From",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+           "paginate": true,
+         },
+         {
+           "header": "To",
+           "prompt": "db987ccfa2a71b2ec9a56c88c77a7cf66d01d8ba",
+           "paginate": true,
+         },
+         {
+           "header": "Amount",
+           "prompt": "POKT 10.0",
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.012",
+         },
+         {
+           "header": "Memo",
+           "prompt": "Fourth transaction",
+           "paginate": true,
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
+         {
+           "text": "Sign Transaction?",
+           "x": 19,
+           "y": 11
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
+           "y": 11,
+         }
+       ]));
+     });
+  it("shows the old upgrade height in expert mode",
+     async () => {
+       const txn = Buffer.from(JSON.stringify(exampleUpgrade), "utf-8");
+       await sendCommandAndAccept(async (client : Pokt) => {
+         const pk = await client.getPublicKey("44'/635'/0/0");
+         await toggleExpertModeSettings();
+         await Axios.delete(BASE_URL + "/events");
+         try {
+           const sig = await client.signTransaction("44'/635'/0/0", txn);
+           await expectValidSignature(sig.signature, txn, pk.publicKey);
+         } finally {
+           await toggleExpertModeSettings();
+         }
+       }, withTransactionHash(txn, [
+         {
+           "header": "Fee Entry",
+           "prompt": "10000 upokt",
+         },
+         {
+           "header": "Message Type",
+           "prompt": "gov/msg_upgrade",
+           "paginate": true,
+         },
+         {
+           "header": "Upgrade",
+           "prompt": "Protocol",
+         },
+         {
+           "header": "Address",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+         },
+         {
+           "header": "Feature",
+           "prompt": "RSCAL:95000",
+         },
+         {
+           "header": "Height",
+           "prompt": "95000",
+         },
+         {
+           "header": "Version",
+           "prompt": "RC-0.9.2",
+         },
+         {
+           "header": "Old Upgrade Height",
+           "prompt": "0",
+           "paginate": true,
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.01",
+         },
+         {
+           "header": "No memo",
+           "prompt": "",
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
+         {
+           "header": "Entropy",
+           "prompt": "4218867327413298166",
+           "paginate": true,
+         },
+         {
+           "text": "Sign Transaction?",
+           "x": 19,
+           "y": 11
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
+           "y": 11,
+         }
+       ]));
+     });
+
+  it("shows the fields of an unknown message when blind signing in expert mode",
+     async () => {
+       const txn = Buffer.from(JSON.stringify(exampleSendUnknownFields), "utf-8");
+       await sendCommandAndAccept(async (client : Pokt) => {
+         const pk = await client.getPublicKey("44'/635'/0/0");
+         await toggleBlindSigningSettings();
+         await toggleExpertModeSettings();
+         await Axios.delete(BASE_URL + "/events");
+         try {
+           const sig = await client.blindSignTransaction("44'/635'/0/0", txn);
+           await expectValidSignature(sig.signature, txn, pk.publicKey);
+         } finally {
+           await toggleExpertModeSettings();
+           await toggleBlindSigningSettings();
+         }
+       }, withTransactionHash(txn, [
+         {
+           "header": "WARNING",
+           "prompt": "Blind Signing a Transaction is a very unusual operation. Do not continue unless you know what you are doing",
+         },
+         {
+           "header": "Sign for Address",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+         },
+         {
+           "header": "Fee Entry",
+           "prompt": "12000 upokt",
+         },
+         {
+           "header": "Message Type",
+           "prompt": "pos/MsgFutureThing",
+           "paginate": true,
+         },
+         {
+           "header": "Unknown Message",
+           "prompt": "pos/MsgFutureThing",
+           "paginate": true,
+         },
+         {
+           "header": "Unknown Field",
+           "prompt": "owner: c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+           "paginate": true,
+         },
+         {
+           "header": "Unknown Field",
+           "prompt": "whatever: 1",
+           "paginate": true,
+         },
+         {
+           "header": "Unknown Field",
+           "prompt": "whatever: 2",
+           "paginate": true,
+         },
+         {
+           "header": "Unknown Field",
+           "prompt": "whatever: 3",
+           "paginate": true,
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.012",
+         },
+         {
+           "header": "Memo",
+           "prompt": "Fourth transaction",
+           "paginate": true,
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
+         {
+           "header": "Entropy",
+           "prompt": "-7780543831205109370",
+           "paginate": true,
+         },
+         {
+           "text": "Blind Sign Transaction?",
+           "x": 4,
+           "y": 11,
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
+           "y": 11,
+         }
+       ]));
+     });
+
+});
+
+function testBlindSignFail(path: string, hash: string) {
+  return async () => {
+    await sendCommandExpectStatus(
+      async (client : Pokt) => {
+        await client.blindSignTransaction(path, hash);
+      }, 0xb008);
+  }
+}
+
+function testBlindSignFail2(path: string, hash: string) {
+  return async () => {
+    await sendCommandExpectStatus(
+      async (client : Pokt) => {
+        // Enable and then disable
+        await toggleBlindSigningSettings();
+        await toggleBlindSigningSettings();
+        await Axios.delete(BASE_URL + "/events");
+        await client.blindSignTransaction(path, hash);
+      }, 0xb008);
+  }
+}
+
+describe("Blind signing tests", function() {
+
+  it("cannot sign arbitary JSON without settings enabled",
+     testBlindSignFail(
+       "44'/635'/0/0",
+       '"ffd8cd79deb956fa3c7d9be0f836f20ac84b140168a087a842be4760e40e2b1c"'
+     ));
+  it("cannot sign abitrary JSON without settings enabled 2",
+     testBlindSignFail2(
+       "44'/635'/0/0",
+       '"ffd8cd79deb956fa3c7d9be0f836f20ac84b140168a087a842be4760e40e2b1c"'
+     ));
+
+  it("can blind sign nonsense JSON",
+     testBlindTransaction(
+       "44'/635'/0/0",
+       {
+         foo: 1,
+         bar: null,
+       },
+       [
+         {
+           "header": "WARNING",
+           "prompt": "Blind Signing a Transaction is a very unusual operation. Do not continue unless you know what you are doing",
+         },
+         {
+           "header": "Sign for Address",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+         },
+         {
+           "header": "WARNING",
+           "prompt": "Could not decode all of this transaction; check the hash",
+           "paginate": true,
+         },
+         {
+           "text": "Blind Sign Transaction?",
+           "x": 4,
+           "y": 11,
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
+           "y": 11,
+         }
+       ]
+     ));
+
+  it("shows the decodable parts of a blind-signed transaction",
+     testBlindTransaction(
+       "44'/635'/0/0",
+       exampleSendUnknownType,
+       [
+         {
+           "header": "WARNING",
+           "prompt": "Blind Signing a Transaction is a very unusual operation. Do not continue unless you know what you are doing",
+         },
+         {
+           "header": "Sign for Address",
+           "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+         },
+         {
+           "header": "Unknown Message",
+           "prompt": "pos/MsgFutureThing",
+           "paginate": true,
+         },
+         {
+           "header": "Fee",
+           "prompt": "POKT 0.012",
+         },
+         {
+           "header": "Memo",
+           "prompt": "Fourth transaction",
+           "paginate": true,
+         },
+         {
+           "header": "Network",
+           "prompt": "Pocket Testnet",
+         },
+         {
+           "text": "Blind Sign Transaction?",
+           "x": 4,
+           "y": 11,
+         },
+         {
+           "text": "Confirm",
+           "x": 43,
+           "y": 11,
+         }
+       ]
+     ));
+
+  it("turns blind signing off after one signature when set to expire",
+     async () => {
+       const path = "44'/635'/0/0";
+       const txn = Buffer.from(JSON.stringify({ foo: 1, bar: null }), "utf-8");
+       await cycleBlindSigningExpirySettings();
+       try {
+         await sendCommandAndAccept(async (client : Pokt) => {
+           await toggleBlindSigningSettings();
+           await Axios.delete(BASE_URL + "/events");
+           await client.blindSignTransaction(path, txn);
+         }, withTransactionHash(txn, [
+           {
+             "header": "WARNING",
+             "prompt": "Blind Signing a Transaction is a very unusual operation. Do not continue unless you know what you are doing",
+           },
+           {
+             "header": "Sign for Address",
+             "prompt": "c2fc52e0bf6fa0686eb1b7afa8d6ab22d7138488",
+           },
+           {
+             "header": "WARNING",
+             "prompt": "Could not decode all of this transaction; check the hash",
+             "paginate": true,
+           },
+           {
+             "text": "Blind Sign Transaction?",
+             "x": 4,
+             "y": 11,
+           },
+           {
+             "text": "Confirm",
+             "x": 43,
+             "y": 11,
+           }
+         ]));
+         await sendCommandExpectStatus(async (client : Pokt) => {
+           await client.blindSignTransaction(path, txn);
+         }, 0xb008);
+       } finally {
+         // Back round to never expiring; changing the expiry also leaves blind signing off.
+         await cycleBlindSigningExpirySettings();
+         await cycleBlindSigningExpirySettings();
+       }
+     });
+});
+
+describe("Block protocol tests", function() {
+  it("resets and reports it when the host can't supply a chunk", async () => {
+    await sendCommandExpectStatus(async (client : Pokt) => {
+      await client.transport.send(0x00, 0x03, 0x00, 0x00, Buffer.from([0x02]));
+    }, 0xb001);
+  });
+
+  it("rejects an unknown block protocol command", async () => {
+    await sendCommandExpectStatus(async (client : Pokt) => {
+      await client.transport.send(0x00, 0x03, 0x00, 0x00, Buffer.from([0x07]));
+    }, 0xb004);
+  });
 });